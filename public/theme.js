@@ -0,0 +1,14 @@
+// Sets the daisyUI theme before the first paint, from the color mode and theme names the app
+// stored in localStorage, so there is no flash of the wrong theme while it loads.
+(function () {
+  try {
+    var mode = localStorage.getItem("kratos-color-mode") || "auto";
+    var themes = (localStorage.getItem("kratos-themes") || "light,dark").split(",");
+    var dark =
+      mode === "dark" ||
+      (mode === "auto" && window.matchMedia("(prefers-color-scheme: dark)").matches);
+    document.documentElement.setAttribute("data-theme", dark ? themes[1] : themes[0]);
+  } catch (err) {
+    // localStorage is unavailable, the app sets the theme once it has loaded.
+  }
+})();
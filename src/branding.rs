@@ -0,0 +1,195 @@
+use crate::browser::local_storage;
+use dioxus::logger::tracing::error;
+use dioxus::prelude::*;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// Reports whether the system prefers a dark color scheme, now and whenever that changes.
+const PREFERS_DARK_JS: &str = r#"
+const query = window.matchMedia("(prefers-color-scheme: dark)");
+dioxus.send(query.matches);
+query.addEventListener("change", (event) => dioxus.send(event.matches));
+"#;
+
+/// `localStorage` keys of the color mode the user picked, and of the light and dark theme names.
+/// `public/theme.js` reads them to set the theme before the app has loaded.
+const COLOR_MODE_KEY: &str = "kratos-color-mode";
+const THEMES_KEY: &str = "kratos-themes";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+  Light,
+  Dark,
+  /// Follows the system's `prefers-color-scheme`.
+  #[default]
+  Auto,
+}
+
+impl ColorMode {
+  pub const ALL: [ColorMode; 3] = [ColorMode::Light, ColorMode::Dark, ColorMode::Auto];
+
+  fn as_str(self) -> &'static str {
+    match self {
+      ColorMode::Light => "light",
+      ColorMode::Dark => "dark",
+      ColorMode::Auto => "auto",
+    }
+  }
+}
+
+impl FromStr for ColorMode {
+  type Err = String;
+
+  fn from_str(mode: &str) -> Result<Self, Self::Err> {
+    match mode.trim().to_lowercase().as_str() {
+      "light" => Ok(ColorMode::Light),
+      "dark" => Ok(ColorMode::Dark),
+      "auto" => Ok(ColorMode::Auto),
+      mode => Err(format!("Unknown color mode {mode}")),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct FooterLink {
+  pub label: String,
+  pub href: String,
+}
+
+/// Product name, logo, colors and links the UI is shown with, part of the runtime configuration
+/// and provided as context by [`use_branding_provider`].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Branding {
+  pub product_name: String,
+  pub logo_url: Option<String>,
+  /// CSS color replacing the daisyUI theme's primary color.
+  pub primary_color: Option<String>,
+  /// daisyUI theme used in light mode.
+  pub light_theme: String,
+  /// daisyUI theme used in dark mode.
+  pub dark_theme: String,
+  /// Initial color mode, until the user picks one.
+  pub color_mode: ColorMode,
+  pub footer_links: Vec<FooterLink>,
+  /// daisyUI classes of the buttons and text inputs in Kratos forms.
+  pub button_class: String,
+  pub input_class: String,
+}
+
+impl Default for Branding {
+  fn default() -> Self {
+    Branding {
+      product_name: "Ory".to_owned(),
+      logo_url: None,
+      primary_color: None,
+      light_theme: "light".to_owned(),
+      dark_theme: "dark".to_owned(),
+      color_mode: ColorMode::Auto,
+      footer_links: Vec::new(),
+      button_class: "btn btn-primary".to_owned(),
+      input_class: "input".to_owned(),
+    }
+  }
+}
+
+impl Branding {
+  /// The daisyUI theme for `mode`, given whether the system prefers dark.
+  fn theme(&self, mode: ColorMode, prefers_dark: bool) -> &str {
+    match mode {
+      ColorMode::Light => &self.light_theme,
+      ColorMode::Dark => &self.dark_theme,
+      ColorMode::Auto if prefers_dark => &self.dark_theme,
+      ColorMode::Auto => &self.light_theme,
+    }
+  }
+
+  /// Sets the theme and primary color on `<html>`, and remembers the theme names for the next
+  /// page load.
+  fn apply(&self, mode: ColorMode, prefers_dark: bool) {
+    let Some(html) = web_sys::window()
+      .and_then(|window| window.document())
+      .and_then(|document| document.document_element())
+    else {
+      error!("Could not access document element");
+      return;
+    };
+
+    if let Err(err) = html.set_attribute("data-theme", self.theme(mode, prefers_dark)) {
+      error!("{err:?}");
+    }
+    if let Some(storage) = local_storage()
+      && let Err(err) = storage.set_item(
+        THEMES_KEY,
+        &format!("{},{}", self.light_theme, self.dark_theme),
+      )
+    {
+      error!("{err:?}");
+    }
+    if let Some(color) = &self.primary_color
+      && let Err(err) = html.set_attribute("style", &format!("--color-primary: {color};"))
+    {
+      error!("{err:?}");
+    }
+  }
+}
+
+/// The color mode the user picked, provided as context by [`use_branding_provider`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorScheme {
+  mode: Signal<ColorMode>,
+}
+
+impl ColorScheme {
+  pub fn mode(&self) -> ColorMode {
+    *self.mode.read()
+  }
+
+  /// Switches to `mode` and remembers it for the next visit.
+  pub fn set_mode(&self, mode: ColorMode) {
+    let mut current = self.mode;
+    current.set(mode);
+    if let Some(storage) = local_storage()
+      && let Err(err) = storage.set_item(COLOR_MODE_KEY, mode.as_str())
+    {
+      error!("{err:?}");
+    }
+  }
+}
+
+pub fn use_branding() -> Branding {
+  use_context()
+}
+
+pub fn use_color_scheme() -> ColorScheme {
+  use_context()
+}
+
+/// Provides `branding` and the [`ColorScheme`], starting with the mode the user picked before,
+/// and applies the theme, following the system's color scheme in auto mode.
+pub fn use_branding_provider(branding: Branding) -> Branding {
+  let branding = use_context_provider(|| branding);
+  let scheme = use_context_provider(|| {
+    let mode = local_storage()
+      .and_then(|storage| storage.get_item(COLOR_MODE_KEY).ok()?)
+      .and_then(|mode| mode.parse().ok())
+      .unwrap_or(branding.color_mode);
+    ColorScheme {
+      mode: Signal::new(mode),
+    }
+  });
+
+  let mut prefers_dark = use_signal(|| false);
+  use_future(move || async move {
+    let mut events = document::eval(PREFERS_DARK_JS);
+    while let Ok(dark) = events.recv::<bool>().await {
+      prefers_dark.set(dark);
+    }
+  });
+
+  let theme_branding = branding.to_owned();
+  use_effect(move || theme_branding.apply(scheme.mode(), prefers_dark()));
+
+  branding
+}
@@ -0,0 +1,126 @@
+use dioxus::logger::tracing::error;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{JSON, Promise};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Headers, RequestCredentials, RequestInit, Response};
+
+#[derive(Debug)]
+pub enum FetchError {
+  Js(String),
+  Status(u16),
+  Json(serde_json::Error),
+}
+
+impl fmt::Display for FetchError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FetchError::Js(err) => write!(f, "{err}"),
+      FetchError::Status(status) => write!(f, "Request failed with status {status}"),
+      FetchError::Json(err) => write!(f, "Invalid JSON: {err}"),
+    }
+  }
+}
+
+impl From<JsValue> for FetchError {
+  fn from(value: JsValue) -> Self {
+    FetchError::Js(format!("{value:?}"))
+  }
+}
+
+/// Decodes a plain JavaScript object by round-tripping it through `JSON.stringify`.
+pub fn from_js_value<T: DeserializeOwned>(value: &JsValue) -> Result<T, FetchError> {
+  let json = JSON::stringify(value)?.as_string().unwrap_or_default();
+  serde_json::from_str(&json).map_err(FetchError::Json)
+}
+
+async fn fetch_json<T: DeserializeOwned>(
+  method: &str,
+  url: &str,
+  body: Option<String>,
+) -> Result<T, FetchError> {
+  let window = web_sys::window().ok_or_else(|| FetchError::Js("Could not access window".into()))?;
+
+  let headers = Headers::new()?;
+  headers.set("Accept", "application/json")?;
+
+  let init = RequestInit::new();
+  init.set_method(method);
+  init.set_credentials(RequestCredentials::Include);
+  if let Some(body) = body {
+    headers.set("Content-Type", "application/json")?;
+    init.set_body(&JsValue::from_str(&body));
+  }
+  init.set_headers(&headers);
+
+  let response = JsFuture::from(window.fetch_with_str_and_init(url, &init)).await?;
+  let response = response.dyn_into::<Response>()?;
+
+  if !response.ok() {
+    return Err(FetchError::Status(response.status()));
+  }
+
+  from_js_value(&JsFuture::from(response.json()?).await?)
+}
+
+/// `GET`s `url`, including the browser's cookies, and decodes the JSON response.
+pub async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T, FetchError> {
+  fetch_json("GET", url, None).await
+}
+
+/// `POST`s `body` as JSON to `url`, including the browser's cookies, and decodes the JSON response.
+pub async fn post_json<T: DeserializeOwned>(
+  url: &str,
+  body: &impl Serialize,
+) -> Result<T, FetchError> {
+  let body = serde_json::to_string(body).map_err(FetchError::Json)?;
+  fetch_json("POST", url, Some(body)).await
+}
+
+/// Writes `text` to the system clipboard.
+pub async fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+  let window = web_sys::window().ok_or_else(|| JsValue::from_str("Could not access window"))?;
+  JsFuture::from(window.navigator().clipboard().write_text(text)).await?;
+  Ok(())
+}
+
+/// Resolves after `ms` milliseconds.
+pub async fn sleep(ms: i32) {
+  let promise = Promise::new(&mut |resolve, _| {
+    if let Some(window) = web_sys::window()
+      && let Err(err) = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+    {
+      error!("{err:?}");
+    }
+  });
+
+  if let Err(err) = JsFuture::from(promise).await {
+    error!("{err:?}");
+  }
+}
+
+/// The browser's `localStorage`, unless it is disabled.
+pub fn local_storage() -> Option<web_sys::Storage> {
+  web_sys::window()?.local_storage().ok()?
+}
+
+/// The page's full URL, e.g. to come back to it through a flow's `return_to`.
+pub fn current_url() -> String {
+  web_sys::window()
+    .and_then(|window| window.location().href().ok())
+    .unwrap_or_default()
+}
+
+/// Leaves the app for `url` with a full page load, e.g. to hand control back to Ory Hydra.
+pub fn redirect(url: &str) {
+  let Some(window) = web_sys::window() else {
+    error!("Could not access window");
+    return;
+  };
+
+  if let Err(err) = window.location().set_href(url) {
+    error!("Failed to redirect to {url}: {err:?}");
+  }
+}
@@ -0,0 +1,280 @@
+use crate::Route;
+use crate::components::{DisplayError, FormBuilder};
+use crate::config::AppConfig;
+use crate::continue_with::{Next, continue_with};
+use crate::i18n::{I18n, use_i18n};
+use crate::kratos::{Kratos, use_kratos};
+use crate::session::use_session;
+use dioxus::logger::tracing::{debug, error};
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::{Error, ResponseContent};
+use ory_kratos_client_wasm::models::{ContinueWith, UiContainer};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+/// Outcome of [`SelfServiceFlow::update`].
+pub enum Submitted<F> {
+  /// The flow with updated UI, e.g. validation messages or a sent code.
+  Flow(F),
+  /// The flow is complete. Follow `continue_with` if it has an action for the browser, or else
+  /// continue at `return_to` if the flow has one, or else at `next`.
+  Done {
+    continue_with: Vec<ContinueWith>,
+    return_to: Option<String>,
+    next: Route,
+  },
+  /// Kratos requires a full page load, e.g. to sign in with an OIDC provider.
+  Redirect(String),
+  /// The session has to be re-authenticated or stepped up first, after which the user returns to
+  /// the flow.
+  Reauthenticate(Route),
+  /// The flow expired and has to be restarted.
+  Expired,
+}
+
+impl<F> Submitted<F> {
+  pub fn map<G>(self, f: impl FnOnce(F) -> G) -> Submitted<G> {
+    match self {
+      Submitted::Flow(flow) => Submitted::Flow(f(flow)),
+      Submitted::Done {
+        continue_with,
+        return_to,
+        next,
+      } => Submitted::Done {
+        continue_with,
+        return_to,
+        next,
+      },
+      Submitted::Redirect(url) => Submitted::Redirect(url),
+      Submitted::Reauthenticate(route) => Submitted::Reauthenticate(route),
+      Submitted::Expired => Submitted::Expired,
+    }
+  }
+}
+
+/// Lets `classify` turn the error responses every `update_*_flow` shares, such as `400` with the
+/// invalid flow, into a [`Submitted`]. Anything it hands back remains an error, boxed as the
+/// response enums hold whole flows.
+pub fn submitted<F, E>(
+  err: Error<E>,
+  classify: impl FnOnce(E) -> Result<Submitted<F>, Box<E>>,
+) -> Result<Submitted<F>, Error<E>> {
+  match err {
+    Error::ResponseError(ResponseContent {
+      status,
+      content,
+      entity: Some(entity),
+    }) => classify(entity).map_err(|entity| {
+      Error::ResponseError(ResponseContent {
+        status,
+        content,
+        entity: Some(*entity),
+      })
+    }),
+    err => Err(err),
+  }
+}
+
+/// A Kratos self-service flow that can be rendered by [`FlowPage`].
+pub trait SelfServiceFlow: Clone + Debug + PartialEq + 'static {
+  /// Parameters passed to [`SelfServiceFlow::create`], e.g. query arguments of the route.
+  type Params: Clone + Debug + Default + PartialEq + 'static;
+  type CreateError: Clone + Debug + 'static;
+  type GetError: Debug + 'static;
+  /// Body of [`SelfServiceFlow::update`], decoded from the submitted form.
+  type Body: DeserializeOwned + 'static;
+  type UpdateError: Debug + 'static;
+
+  /// Page heading.
+  const TITLE: &'static str;
+  /// Flow name used in error messages.
+  const NAME: &'static str;
+
+  /// Initializes a new browser flow.
+  async fn create(kratos: &Kratos, params: &Self::Params)
+  -> Result<Self, Error<Self::CreateError>>;
+
+  /// Fetches an existing flow by id.
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>>;
+
+  /// Submits `body` to the flow.
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>>;
+
+  /// Route that starts a fresh flow from `params`, used when an existing flow can't be fetched or
+  /// has expired.
+  fn restart(params: &Self::Params) -> Route;
+
+  fn ui(&self) -> &UiContainer;
+
+  /// Actions Kratos attached to an updated flow, e.g. `show_settings_ui` after recovery.
+  fn continue_with(&self) -> &[ContinueWith] {
+    &[]
+  }
+
+  /// Identity schema the flow's trait inputs follow, given the configured `default` schema id.
+  fn identity_schema(&self, _default: &str) -> Option<String> {
+    None
+  }
+
+  /// Rendered below the flow's form, in the user's locale.
+  fn footer(_i18n: &I18n) -> Element {
+    rsx! {}
+  }
+}
+
+enum FlowError<F: SelfServiceFlow> {
+  Create(Error<F::CreateError>),
+  Get(Error<F::GetError>),
+}
+
+/// Creates a new flow from `params`, or fetches the flow with id `flow` if one is given, and
+/// renders its form. Submissions are sent as JSON, so the app is never reloaded.
+#[component]
+pub fn FlowPage<F: SelfServiceFlow>(
+  flow: Option<String>,
+  #[props(default)] params: F::Params,
+) -> Element
+where
+  ResponseContent<F::CreateError>: DisplayError,
+{
+  let kratos = use_kratos();
+  let update_kratos = kratos.clone();
+  let session = use_session();
+  let i18n = use_i18n();
+  let config = use_context::<AppConfig>();
+  let nav = navigator();
+  let restart = F::restart(&params);
+  let update_restart = restart.to_owned();
+  // Re-run when the query changes while the page stays mounted, e.g. `/sign-in` to
+  // `/sign-in?aal=aal2`.
+  let fetch_flow = use_resource(use_reactive!(|(flow, params)| {
+    let kratos = kratos.to_owned();
+    async move {
+      match flow {
+        Some(id) => F::get(&kratos, &id).await.map_err(FlowError::<F>::Get),
+        None => F::create(&kratos, &params)
+          .await
+          .map_err(FlowError::<F>::Create),
+      }
+    }
+  }));
+
+  // The flow as returned by the last submission, until a different flow is loaded.
+  let mut submitted_flow = use_signal(|| None::<F>);
+  let mut failure = use_signal(|| None::<String>);
+  use_effect(move || {
+    let _ = fetch_flow.read();
+    submitted_flow.set(None);
+    failure.set(None);
+  });
+
+  let name = F::NAME;
+
+  let submit = move |body: serde_json::Value| {
+    let current = submitted_flow().or_else(|| match &*fetch_flow.read() {
+      Some(Ok(flow)) => Some(flow.to_owned()),
+      _ => None,
+    });
+    let Some(current) = current else {
+      return;
+    };
+
+    let body = match serde_json::from_value::<F::Body>(body) {
+      Ok(body) => body,
+      Err(err) => {
+        error!("Invalid {name} submission: {err}");
+        failure.set(Some(
+          i18n.t_with("Failed to submit {name}.", &[("name", name)]),
+        ));
+        return;
+      }
+    };
+
+    let kratos = update_kratos.to_owned();
+    let session = session.to_owned();
+    let restart = update_restart.to_owned();
+    spawn(async move {
+      let result = F::update(&kratos, &current, body).await;
+      // Signing in, registering or changing settings may all have changed the session.
+      if result.is_ok() {
+        session.refresh().await;
+      }
+
+      match result {
+        Ok(Submitted::Flow(flow)) => match continue_with(flow.continue_with()) {
+          Some(next) => next.go(nav),
+          None => {
+            failure.set(None);
+            submitted_flow.set(Some(flow));
+          }
+        },
+        Ok(Submitted::Done {
+          continue_with: items,
+          return_to,
+          next,
+        }) => continue_with(&items)
+          .or_else(|| return_to.as_deref().map(Next::url))
+          .unwrap_or(Next::Route(next))
+          .go(nav),
+        Ok(Submitted::Redirect(url)) => Next::url(&url).go(nav),
+        Ok(Submitted::Reauthenticate(route)) => Next::Route(route).go(nav),
+        Ok(Submitted::Expired) => {
+          nav.replace(restart);
+        }
+        Err(err) => {
+          error!("{err:#?}");
+          failure.set(Some(
+            i18n.t_with("Failed to submit {name}.", &[("name", name)]),
+          ));
+        }
+      }
+    });
+  };
+
+  return match &*fetch_flow.read() {
+    Some(Ok(res)) => {
+      let flow = submitted_flow().unwrap_or_else(|| res.to_owned());
+      debug!("{flow:#?}");
+      rsx! {
+        h1 { class: "text-center text-2xl", {i18n.t(F::TITLE)} }
+        div { class: "mx-auto w-full max-w-lg",
+          div { class: "mt-10",
+            if let Some(failure) = failure() {
+              div { role: "alert", class: "alert alert-error",
+                span { {failure} }
+              }
+            }
+            FormBuilder {
+              ui: flow.ui().to_owned(),
+              onsubmit: submit,
+              schema_id: flow.identity_schema(&config.identity_schema_id),
+            }
+            {F::footer(&i18n)}
+          }
+        }
+      }
+    }
+    Some(Err(FlowError::Create(Error::ResponseError(res)))) => rsx! {
+      {res.to_owned().view_response_content()}
+    },
+    Some(Err(FlowError::Create(err))) => {
+      error!("{err:#?}");
+      rsx! {
+        p { {i18n.t_with("Failed to create {name}! Error:", &[("name", name)])} }
+        p { "{err:#?}" }
+      }
+    }
+    Some(Err(FlowError::Get(err))) => {
+      error!("{err:#?}");
+      navigator().replace(restart);
+      rsx! {
+        p { {i18n.t_with("Failed to get {name}! Error:", &[("name", name)])} " {err:?}" }
+      }
+    }
+    None => rsx! {},
+  };
+}
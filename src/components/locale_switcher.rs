@@ -0,0 +1,20 @@
+use crate::i18n::use_i18n;
+use dioxus::prelude::*;
+
+/// Lets the user pick the UI language, which is remembered for their next visit.
+#[component]
+pub fn LocaleSwitcher() -> Element {
+  let i18n = use_i18n();
+  let current = i18n.locale();
+
+  rsx! {
+    label { class: "select select-sm w-full",
+      span { class: "label", {i18n.t("Language")} }
+      select { onchange: move |evt: FormEvent| i18n.set_locale(&evt.value()),
+        for (locale, name) in i18n.locales() {
+          option { key: "{locale}", value: locale, selected: locale == current, {name} }
+        }
+      }
+    }
+  }
+}
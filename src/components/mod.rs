@@ -1,12 +1,37 @@
+mod flow_page;
+pub use flow_page::FlowPage;
+pub use flow_page::SelfServiceFlow;
+pub use flow_page::Submitted;
+pub use flow_page::submitted;
+
 mod ory_form_builder;
 pub use ory_form_builder::FormBuilder;
 
 mod ory_error;
 pub use ory_error::DisplayError;
 
+mod ory_lookup_secret;
+mod ory_oidc;
+mod ory_password;
+mod ory_totp;
+
+mod locale_switcher;
+pub use locale_switcher::LocaleSwitcher;
+
 mod ory_log_out;
 pub use ory_log_out::OryLogOut;
 
-mod session_cookie;
-pub use session_cookie::SetSessionCookie;
-pub use session_cookie::session_cookie_valid;
+mod refresh_session;
+pub use refresh_session::RefreshSession;
+
+mod route_guard;
+pub use route_guard::RouteGuard;
+
+mod session_expiry;
+pub use session_expiry::SessionExpiry;
+
+mod theme_switcher;
+pub use theme_switcher::ThemeSwitcher;
+
+mod timestamp;
+pub use timestamp::Timestamp;
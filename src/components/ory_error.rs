@@ -1,4 +1,5 @@
 use crate::Route;
+use crate::browser::current_url;
 use dioxus::prelude::*;
 use ory_kratos_client_wasm::apis::ResponseContent;
 use ory_kratos_client_wasm::apis::frontend_api::{
@@ -8,7 +9,18 @@ use ory_kratos_client_wasm::apis::frontend_api::{
 };
 use ory_kratos_client_wasm::models::error_generic::ErrorGeneric;
 
+/// Kratos error id returned when the session has to be stepped up to AAL2, e.g. because of
+/// `session.whoami.required_aal` or `selfservice.flows.settings.required_aal`.
+const SESSION_AAL2_REQUIRED: &str = "session_aal2_required";
+
 fn error_content_rsx(err: ErrorGeneric) -> Element {
+  if err.error.id.as_deref() == Some(SESSION_AAL2_REQUIRED) {
+    navigator().replace(Route::SecondFactor {
+      return_to: current_url(),
+    });
+    return rsx! {};
+  }
+
   rsx! {
     div { class: "text-center max-h-screen max-w-none",
       h1 { class: "text-2xl my-8", {err.error.message} }
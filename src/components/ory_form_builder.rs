@@ -1,21 +1,295 @@
+use super::ory_lookup_secret::LookupSecretNodes;
+use super::ory_oidc::OidcNodes;
+use super::ory_password::PasswordNode;
+use super::ory_totp::TotpNodes;
+use crate::branding::use_branding;
+use crate::i18n::use_i18n;
+use crate::identity_schema;
+use crate::kratos::use_kratos;
+use crate::webauthn;
 use dioxus::logger::tracing::error;
 use dioxus::prelude::*;
 use ory_kratos_client_wasm::models::UiNodeAttributes::{A, Div, Img, Input, Script, Text};
+use ory_kratos_client_wasm::models::ui_node::GroupEnum;
+use ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+use web_sys::js_sys::{Array, try_iter};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{AbortController, FormData, HtmlFormElement, SubmitEvent};
+
+/// JSON type a submitted field is sent as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FieldKind {
+  Bool,
+  Number,
+  String,
+}
+
+/// Kind and group of each input node, by name.
+type Fields = HashMap<String, (FieldKind, GroupEnum)>;
+
+fn fields(nodes: &[ory_kratos_client_wasm::models::UiNode]) -> Fields {
+  nodes
+    .iter()
+    .filter_map(|node| {
+      let Input(i) = &*node.attributes else {
+        return None;
+      };
+      // Buttons such as `lookup_secret_regenerate` carry a boolean value.
+      let kind = match (i.r#type, &i.value) {
+        (TypeEnum::Checkbox, _) | (_, Some(Some(Value::Bool(_)))) => FieldKind::Bool,
+        (TypeEnum::Number, _) => FieldKind::Number,
+        _ => FieldKind::String,
+      };
+      Some((i.name.to_owned(), (kind, node.group)))
+    })
+    .collect()
+}
+
+/// Inserts `value` at a dotted `name` such as `traits.name.first`.
+fn insert_field(body: &mut Map<String, Value>, name: &str, value: Value) {
+  match name.split_once('.') {
+    Some((head, rest)) => {
+      if let Value::Object(child) = body
+        .entry(head)
+        .or_insert_with(|| Value::Object(Map::new()))
+      {
+        insert_field(child, rest, value);
+      }
+    }
+    None => {
+      body.insert(name.to_owned(), value);
+    }
+  }
+}
+
+/// Serializes a submitted form into the JSON body of Kratos' `update_*_flow` endpoints.
+///
+/// Values are typed after their nodes, and `method` defaults to the group of the submitted
+/// fields, as the browser only sends it when a method button was clicked.
+fn form_body(evt: &FormEvent, fields: &Fields) -> Result<Value, JsValue> {
+  let event = evt
+    .downcast::<web_sys::Event>()
+    .and_then(|event| event.dyn_ref::<SubmitEvent>())
+    .ok_or_else(|| JsValue::from_str("Not a submit event"))?;
+  let form = event
+    .target()
+    .ok_or_else(|| JsValue::from_str("Submit event without form"))?
+    .dyn_into::<HtmlFormElement>()?;
+  let data = FormData::new_with_form(&form)?;
+  // The clicked button, e.g. the chosen method, is only included by `new FormData(form,
+  // submitter)`, which web-sys doesn't bind.
+  if let Some(submitter) = event.submitter()
+    && let Some(name) = submitter.get_attribute("name")
+  {
+    let value = submitter.get_attribute("value").unwrap_or_default();
+    data.append_with_str(&name, &value)?;
+  }
+
+  let mut body = Map::new();
+  let mut group = None;
+  for entry in try_iter(&data)?.ok_or_else(|| JsValue::from_str("FormData is not iterable"))? {
+    let entry = Array::from(&entry?);
+    let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string()) else {
+      continue;
+    };
+
+    let field = fields.get(&name);
+    if let Some((_, field_group)) = field
+      && *field_group != GroupEnum::Default
+    {
+      group.get_or_insert(*field_group);
+    }
+
+    let value = match field.map(|(kind, _)| kind) {
+      Some(FieldKind::Bool) => Value::Bool(value == "true" || value == "on"),
+      Some(FieldKind::Number) => match value.parse() {
+        Ok(number) => Value::Number(number),
+        Err(_) => Value::String(value),
+      },
+      _ => Value::String(value),
+    };
+    insert_field(&mut body, &name, value);
+  }
+
+  if !body.contains_key("method")
+    && let Some(group) = group
+  {
+    body.insert(
+      "method".to_owned(),
+      serde_json::to_value(group).map_err(|err| JsValue::from_str(&err.to_string()))?,
+    );
+  }
+
+  Ok(Value::Object(body))
+}
+
+/// The identity schema a [`FormBuilder`] renders trait inputs from, provided as context.
+#[derive(Clone, Copy)]
+struct TraitsSchema(Resource<Option<Value>>);
+
+impl TraitsSchema {
+  fn property(&self, path: &str) -> Option<Value> {
+    let schema = self.0.read();
+    identity_schema::property(Option::as_ref(&schema)?.as_ref()?, path).cloned()
+  }
+
+  /// The schema of the trait input `name`, e.g. `traits.name.first`.
+  fn input(&self, name: &str) -> Option<Value> {
+    self.property(name.strip_prefix("traits.")?)
+  }
+
+  /// The object the trait input `node` belongs to, unless that is `traits` itself, and its schema.
+  fn object(&self, node: &ory_kratos_client_wasm::models::UiNode) -> Option<(String, Value)> {
+    let Input(input) = &*node.attributes else {
+      return None;
+    };
+    let (path, _) = input.name.strip_prefix("traits.")?.rsplit_once('.')?;
+    let property = self.property(path)?;
+    (property.get("type").and_then(Value::as_str) == Some("object"))
+      .then(|| (path.to_owned(), property))
+  }
+}
+
+fn schema_str(property: &Value, key: &str) -> Option<String> {
+  property.get(key)?.as_str().map(str::to_owned)
+}
+
+fn schema_number(property: &Value, key: &str) -> Option<String> {
+  property.get(key)?.as_f64().map(|number| number.to_string())
+}
+
+/// Input types a trait's schema can refine, booleans and hidden inputs are left as they are.
+fn is_trait_input(r#type: TypeEnum) -> bool {
+  matches!(
+    r#type,
+    TypeEnum::Text
+      | TypeEnum::Email
+      | TypeEnum::Number
+      | TypeEnum::Tel
+      | TypeEnum::Url
+      | TypeEnum::Date
+      | TypeEnum::DatetimeLocal
+  )
+}
+
+/// The HTML input type for a trait's `format` or `type`.
+fn trait_input_type(property: &Value) -> Option<&'static str> {
+  match schema_str(property, "format").as_deref() {
+    Some("email") => Some("email"),
+    Some("uri") => Some("url"),
+    Some("date") => Some("date"),
+    Some("tel") => Some("tel"),
+    _ => match schema_str(property, "type").as_deref() {
+      Some("integer" | "number") => Some("number"),
+      _ => None,
+    },
+  }
+}
+
+/// A trait input following its identity schema `property`: a select for enums, its description
+/// as help text, and its format, length and bounds as browser validation. `pattern` is left to
+/// Kratos, a JSON Schema pattern is unanchored unlike the HTML attribute.
+#[component]
+fn TraitNode(
+  meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
+  attrs: ory_kratos_client_wasm::models::UiNodeInputAttributes,
+  property: Value,
+) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+
+  let label = match &meta {
+    Some(label) => i18n.text(label),
+    None => schema_str(&property, "title").unwrap_or_else(|| attrs.name.to_owned()),
+  };
+  let value = match attrs.value.flatten() {
+    Some(Value::String(value)) => value,
+    Some(Value::Null) | None => String::new(),
+    Some(value) => value.to_string(),
+  };
+  let options = property
+    .get("enum")
+    .and_then(Value::as_array)
+    .map(|options| {
+      options
+        .iter()
+        .map(|option| match option {
+          Value::String(option) => option.to_owned(),
+          option => option.to_string(),
+        })
+        .collect::<Vec<_>>()
+    });
+  let required = attrs.required.unwrap_or_default();
+  let r#type = trait_input_type(&property)
+    .map(str::to_owned)
+    .unwrap_or_else(|| format!("{:?}", attrs.r#type).to_lowercase());
+  let integer = schema_str(&property, "type").as_deref() == Some("integer");
+
+  rsx! {
+    label { class: "floating-label my-4",
+      span { {label.to_owned()} }
+      if let Some(options) = options {
+        select {
+          class: "select validator w-full",
+          disabled: attrs.disabled,
+          id: if let Some(ref label) = meta { label.id.to_string() },
+          name: attrs.name,
+          required,
+          if !required {
+            option { value: "", "" }
+          }
+          for choice in options {
+            option {
+              key: "{choice}",
+              selected: choice == value,
+              value: choice.to_owned(),
+              {choice.to_owned()}
+            }
+          }
+        }
+      } else {
+        input {
+          autocomplete: if let Some(a) = attrs.autocomplete { format!("{a:?}").to_lowercase() },
+          class: "{branding.input_class} validator w-full",
+          disabled: attrs.disabled,
+          id: if let Some(ref label) = meta { label.id.to_string() },
+          name: attrs.name,
+          placeholder: label,
+          required,
+          r#type,
+          value,
+          minlength: schema_number(&property, "minLength"),
+          maxlength: schema_number(&property, "maxLength"),
+          min: schema_number(&property, "minimum"),
+          max: schema_number(&property, "maximum"),
+          step: if integer { "1" },
+        }
+      }
+      if let Some(description) = schema_str(&property, "description") {
+        p { class: "label text-wrap", {description} }
+      }
+    }
+  }
+}
 
 #[component]
 fn InputFieldNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeInputAttributes,
   validate: bool,
-  pattern: Option<String>,
   hint: Option<Element>,
 ) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
   rsx! {
     label { class: "floating-label my-4",
       span {
         {
             if let Some(ref label) = meta {
-                label.text.clone()
+                i18n.text(label)
             } else {
                 format!("{:?}", attrs.r#type)
             }
@@ -24,14 +298,13 @@ fn InputFieldNode(
       input {
         required: if let Some(r) = attrs.required { r },
         autocomplete: if let Some(a) = attrs.autocomplete { format!("{a:?}").to_lowercase() },
-        class: "input w-full",
+        class: "{branding.input_class} w-full",
         class: if validate { "validator" },
         disabled: attrs.disabled,
         id: if let Some(ref label) = meta { label.id.to_string() },
         name: attrs.name,
-        placeholder: if let Some(ref label) = meta { label.text.clone() } else { format!("{:?}", attrs.r#type) },
+        placeholder: if let Some(ref label) = meta { i18n.text(label) } else { format!("{:?}", attrs.r#type) },
         r#type: format!("{:?}", attrs.r#type).to_lowercase(),
-        pattern: if let Some(pattern) = pattern { pattern },
         value: if let Some(v) = attrs.value { if let Some(serde_json::Value::String(s)) = v { s } else { "".to_string() } },
       }
       if validate {
@@ -50,13 +323,28 @@ fn InputButtonNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeInputAttributes,
 ) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+  // WebAuthn and passkey buttons run a browser ceremony before their form is submitted.
+  let trigger = attrs.onclick_trigger.as_ref().map(webauthn::trigger_name);
+
   rsx! {
     button {
       disabled: attrs.disabled,
-      class: "btn btn-primary w-full my-4",
+      class: "{branding.button_class} w-full my-4",
       id: if let Some(ref label) = meta { label.id.to_string() },
       name: attrs.name,
       r#type: format!("{:?}", attrs.r#type).to_lowercase(),
+      onclick: move |evt: MouseEvent| {
+          if let Some(trigger) = trigger.to_owned() {
+              evt.prevent_default();
+              spawn(async move {
+                  if let Err(err) = webauthn::on_click(&trigger).await {
+                      error!("{trigger} failed: {err:?}");
+                  }
+              });
+          }
+      },
 
       value: if let Some(v) = attrs.value { if let Some(t) = v {
           match t {
@@ -68,7 +356,7 @@ fn InputButtonNode(
       } },
 
       if let Some(ref label) = meta {
-        {label.text.to_string()}
+        {i18n.text(label)}
       }
     }
   }
@@ -79,13 +367,15 @@ fn InputOtherNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeInputAttributes,
 ) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
   rsx! {
     if let Some(ref label) = meta {
       label { id: label.id, class: "w-full",
-        {label.text.to_owned()}
+        {i18n.text(label)}
         input {
           disabled: attrs.disabled,
-          class: "input w-full",
+          class: "{branding.input_class} w-full",
           name: attrs.name,
           r#type: format!("{:?}", attrs.r#type).to_lowercase(),
           value: if let Some(v) = attrs.value { if let Some(t) = v {
@@ -104,7 +394,7 @@ fn InputOtherNode(
     } else {
       input {
         disabled: attrs.disabled,
-        class: "input w-full",
+        class: "{branding.input_class} w-full",
         name: attrs.name,
         r#type: format!("{:?}", attrs.r#type).to_lowercase(),
 
@@ -129,13 +419,15 @@ fn InputCheckBoxNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeInputAttributes,
 ) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
   rsx! {
     if let Some(ref label) = meta {
       label { id: label.id,
-        {label.text.to_owned()}
+        {i18n.text(label)}
         input {
           disabled: attrs.disabled,
-          class: "input w-full",
+          class: "{branding.input_class} w-full",
           name: attrs.name,
           r#type: format!("{:?}", attrs.r#type).to_lowercase(),
           checked: if let Some(v) = attrs.value { if let Some(serde_json::Value::Bool(b)) = v { b } else { false } },
@@ -144,7 +436,7 @@ fn InputCheckBoxNode(
     } else {
       input {
         disabled: attrs.disabled,
-        class: "input",
+        class: branding.input_class,
         name: attrs.name,
         r#type: format!("{:?}", attrs.r#type).to_lowercase(),
         checked: if let Some(v) = attrs.value { if let Some(serde_json::Value::Bool(b)) = v { b } else { false } },
@@ -158,16 +450,17 @@ fn ImageNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeImageAttributes,
 ) -> Element {
+  let i18n = use_i18n();
   rsx! {
     if let Some(ref label) = meta {
       label { id: label.id, class: "text-lg mb-4",
-        {label.text.clone()}
+        {i18n.text(label)}
         img {
           height: attrs.height,
           id: attrs.id,
           src: attrs.src,
           width: attrs.width,
-          alt: label.text.to_owned(),
+          alt: i18n.text(label),
         }
       }
     } else {
@@ -186,13 +479,14 @@ fn TextNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeTextAttributes,
 ) -> Element {
+  let i18n = use_i18n();
   rsx! {
     if let Some(ref label) = meta {
       label { r#for: attrs.id.clone(), id: label.id, class: "text-lg",
-        {label.text.to_owned()}
+        {i18n.text(label)}
       }
     }
-    p { id: attrs.id, class: "", {attrs.text.text} }
+    p { id: attrs.id, class: "", {i18n.text(&attrs.text)} }
   }
 }
 
@@ -201,17 +495,18 @@ fn LinkNode(
   meta: Option<Box<ory_kratos_client_wasm::models::UiText>>,
   attrs: ory_kratos_client_wasm::models::UiNodeAnchorAttributes,
 ) -> Element {
+  let i18n = use_i18n();
   rsx! {
     if let Some(ref label) = meta {
       label { r#for: attrs.id.clone(), id: label.id, class: "text-lg",
-        {label.text.to_owned()}
+        {i18n.text(label)}
       }
     }
     a {
       id: attrs.id,
       class: "link-primary link-hover",
       href: attrs.href,
-      {attrs.title.text}
+      {i18n.text(&attrs.title)}
     }
   }
 }
@@ -232,24 +527,50 @@ fn DivNode(attrs: ory_kratos_client_wasm::models::UiNodeDivisionAttributes) -> E
   }
 }
 
+/// Browsers don't run scripts rendered by Dioxus, so the script is added to `<head>` instead.
 #[component]
 fn ScriptNode(attrs: ory_kratos_client_wasm::models::UiNodeScriptAttributes) -> Element {
-  rsx! {
-    script {
-      r#async: attrs.r#async,
-      crossorigin: attrs.crossorigin,
-      id: attrs.id,
-      integrity: attrs.integrity,
-      nonce: attrs.nonce,
-      referrerpolicy: attrs.referrerpolicy,
-      src: attrs.src,
-      r#type: attrs.r#type,
+  use_effect(move || {
+    if let Err(err) = webauthn::load_script(&attrs) {
+      error!("Failed to load {}: {err:?}", attrs.src);
     }
-  }
+  });
+
+  rsx! {}
+}
+
+/// Runs a node's `onloadTrigger` once the form has rendered, and cancels it when the form goes.
+#[component]
+fn OnLoadTrigger(trigger: String) -> Element {
+  let controller = use_hook(|| AbortController::new().ok());
+
+  let drop_controller = controller.to_owned();
+  use_drop(move || {
+    if let Some(controller) = &drop_controller {
+      controller.abort();
+    }
+  });
+
+  use_effect(move || {
+    let Some(signal) = controller.as_ref().map(AbortController::signal) else {
+      return;
+    };
+    let trigger = trigger.to_owned();
+    spawn(async move {
+      if let Err(err) = webauthn::on_load(&trigger, &signal).await
+        && !signal.aborted()
+      {
+        error!("{trigger} failed: {err:?}");
+      }
+    });
+  });
+
+  rsx! {}
 }
 
 #[component]
 fn MessageNode(message: ory_kratos_client_wasm::models::UiText) -> Element {
+  let i18n = use_i18n();
   rsx! {
     div {
       id: message.id,
@@ -265,138 +586,199 @@ fn MessageNode(message: ory_kratos_client_wasm::models::UiText) -> Element {
               }
           }
       },
-      span { {message.text} }
+      span { {i18n.text(&message)} }
     }
   }
 }
 
+/// Renders one node, with inputs for identity traits following their schema if the
+/// [`FormBuilder`] has it.
 #[component]
-fn NodeBuilder(nodes: Vec<ory_kratos_client_wasm::models::UiNode>) -> Element {
+fn Node(node: ory_kratos_client_wasm::models::UiNode) -> Element {
+  let i18n = use_i18n();
+  let schema = try_use_context::<TraitsSchema>();
+
+  if let Input(i) = &*node.attributes
+    && is_trait_input(i.r#type)
+    && let Some(property) = schema.and_then(|schema| schema.input(&i.name))
+  {
+    return rsx! {
+      TraitNode { meta: node.meta.label, attrs: (**i).to_owned(), property }
+    };
+  }
+
   rsx! {
-    for node in nodes {
-      match *node.attributes {
-          Input(i) => {
-              match i.r#type {
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Text => {
-                      rsx! {
-                        InputFieldNode { meta: node.meta.label, attrs: *i, validate: false }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Password => {
-                      rsx! {
-                        InputFieldNode {
-                          meta: node.meta.label,
-                          attrs: *i,
-                          validate: true,
-                          hint: rsx! {
-                            "Password must be more than 8 characters, and include:"
-                            ul { class: "list-disc list-inside",
-                              li { "At least one number" }
-                              li { "At least one lowercase letter" }
-                              li { "At least one uppercase letter" }
+    match *node.attributes {
+        Input(i) => {
+            match i.r#type {
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Text => {
+                    rsx! {
+                      InputFieldNode { meta: node.meta.label, attrs: *i, validate: false }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Password => {
+                    rsx! {
+                      PasswordNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Number => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Checkbox => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Hidden => {
+                    rsx! {
+                      input {
+                        autocomplete: if let Some(a) = i.autocomplete { format!("{a:?}").to_lowercase() },
+                        disabled: i.disabled,
+                        name: i.name,
+                        id: if let Some(ref label) = node.meta.label { format!("{}", label.id) },
+                        r#type: format!("{:?}", i.r#type).to_lowercase(),
+                        value: if let Some(v) = i.value { if let Some(t) = v {
+                            match t {
+                                serde_json::Value::String(s) => s,
+                                _ => "".to_string(),
                             }
-                          },
-                          pattern: "(?=.*\\d)(?=.*[a-z])(?=.*[A-Z]).{{8,}}",
-                          // title: "Must be more than 8 characters, including number, lowercase letter, uppercase letter"
-                        }
+                        } else {
+                            "".to_string()
+                        } },
                       }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Number => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Checkbox => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Hidden => {
-                      rsx! {
-                        input {
-                          autocomplete: if let Some(a) = i.autocomplete { format!("{a:?}").to_lowercase() },
-                          disabled: i.disabled,
-                          name: i.name,
-                          id: if let Some(ref label) = node.meta.label { format!("{}", label.id) },
-                          r#type: format!("{:?}", i.r#type).to_lowercase(),
-                          value: if let Some(v) = i.value { if let Some(t) = v {
-                              match t {
-                                  serde_json::Value::String(s) => s,
-                                  _ => "".to_string(),
-                              }
-                          } else {
-                              "".to_string()
-                          } },
-                        }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Email => {
-                      rsx! {
-                        InputFieldNode {
-                          meta: node.meta.label,
-                          attrs: *i,
-                          validate: true,
-                          hint: rsx! {
-                            p { "Enter valid email address" }
-                          },
-                        }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Tel => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Submit => {
-                      rsx! {
-                        InputButtonNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Button => {
-                      rsx! {
-                        InputButtonNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::DatetimeLocal => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
-                      }
-                  }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Date => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Email => {
+                    rsx! {
+                      InputFieldNode {
+                        meta: node.meta.label,
+                        attrs: *i,
+                        validate: true,
+                        hint: rsx! {
+                          p { {i18n.t("Enter valid email address")} }
+                        },
                       }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Tel => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Submit => {
+                    rsx! {
+                      InputButtonNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Button => {
+                    rsx! {
+                      InputButtonNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::DatetimeLocal => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Date => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+                ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Url => {
+                    rsx! {
+                      InputOtherNode { meta: node.meta.label, attrs: *i }
+                    }
+                }
+            }
+        }
+        Text(text) => {
+            rsx! {
+              TextNode { meta: node.meta.label, attrs: *text }
+            }
+        }
+        Img(img) => {
+            rsx! {
+              ImageNode { meta: node.meta.label, attrs: *img }
+            }
+        }
+        A(link) => {
+            rsx! {
+              LinkNode { meta: node.meta.label, attrs: *link }
+            }
+        }
+        Div(div) => {
+            rsx! {
+              DivNode { attrs: *div }
+            }
+        }
+        Script(script) => {
+            rsx! {
+              ScriptNode { attrs: *script }
+            }
+        }
+    }
+  }
+}
+
+enum NodeItem {
+  Node(ory_kratos_client_wasm::models::UiNode),
+  /// Traits of an object in the identity schema, such as `traits.name`.
+  Object {
+    path: String,
+    property: Value,
+    nodes: Vec<ory_kratos_client_wasm::models::UiNode>,
+  },
+}
+
+/// Renders nodes, grouping the traits of an object in the identity schema in a fieldset.
+#[component]
+pub(super) fn NodeBuilder(nodes: Vec<ory_kratos_client_wasm::models::UiNode>) -> Element {
+  let schema = try_use_context::<TraitsSchema>();
+
+  let mut items = Vec::<NodeItem>::new();
+  for node in nodes {
+    let object = schema.and_then(|schema| schema.object(&node));
+    let same_object = matches!(
+      (items.last(), &object),
+      (Some(NodeItem::Object { path, .. }), Some((object_path, _))) if path == object_path
+    );
+
+    if same_object && let Some(NodeItem::Object { nodes, .. }) = items.last_mut() {
+      nodes.push(node);
+    } else {
+      items.push(match object {
+        Some((path, property)) => NodeItem::Object {
+          path,
+          property,
+          nodes: vec![node],
+        },
+        None => NodeItem::Node(node),
+      });
+    }
+  }
+
+  rsx! {
+    for item in items {
+      match item {
+          NodeItem::Node(node) => rsx! {
+            Node { node }
+          },
+          NodeItem::Object { path, property, nodes } => {
+              let title = schema_str(&property, "title")
+                  .unwrap_or_else(|| path.rsplit('.').next().unwrap_or_default().to_owned());
+              rsx! {
+                fieldset { class: "fieldset border border-base-300 rounded-box p-4 my-2",
+                  legend { class: "fieldset-legend", {title} }
+                  if let Some(description) = schema_str(&property, "description") {
+                    p { class: "label text-wrap", {description} }
                   }
-                  ory_kratos_client_wasm::models::ui_node_input_attributes::TypeEnum::Url => {
-                      rsx! {
-                        InputOtherNode { meta: node.meta.label, attrs: *i }
-                      }
+                  for node in nodes {
+                    Node { node }
                   }
-              }
-          }
-          Text(text) => {
-              rsx! {
-                TextNode { meta: node.meta.label, attrs: *text }
-              }
-          }
-          Img(img) => {
-              rsx! {
-                ImageNode { meta: node.meta.label, attrs: *img }
-              }
-          }
-          A(link) => {
-              rsx! {
-                LinkNode { meta: node.meta.label, attrs: *link }
-              }
-          }
-          Div(div) => {
-              rsx! {
-                DivNode { attrs: *div }
-              }
-          }
-          Script(script) => {
-              rsx! {
-                ScriptNode { attrs: *script }
+                }
               }
           }
       }
@@ -404,8 +786,69 @@ fn NodeBuilder(nodes: Vec<ory_kratos_client_wasm::models::UiNode>) -> Element {
   }
 }
 
+/// Renders a group's nodes, using a dedicated component for groups that need more than the
+/// generic node rendering.
 #[component]
-pub fn FormBuilder(ui: ory_kratos_client_wasm::models::UiContainer) -> Element {
+fn GroupBuilder(nodes: Vec<ory_kratos_client_wasm::models::UiNode>) -> Element {
+  match nodes.first().map(|node| node.group) {
+    Some(ory_kratos_client_wasm::models::ui_node::GroupEnum::Totp) => rsx! {
+      TotpNodes { nodes }
+    },
+    Some(ory_kratos_client_wasm::models::ui_node::GroupEnum::LookupSecret) => rsx! {
+      LookupSecretNodes { nodes }
+    },
+    Some(ory_kratos_client_wasm::models::ui_node::GroupEnum::Oidc) => rsx! {
+      OidcNodes { nodes }
+    },
+    _ => rsx! {
+      NodeBuilder { nodes }
+    },
+  }
+}
+
+/// Renders a flow's forms. With `onsubmit`, submissions are intercepted and passed on as a JSON
+/// body instead of being posted by the browser. With `schema_id`, trait inputs follow that
+/// identity schema.
+#[component]
+pub fn FormBuilder(
+  ui: ory_kratos_client_wasm::models::UiContainer,
+  onsubmit: Option<EventHandler<Value>>,
+  schema_id: Option<String>,
+) -> Element {
+  let kratos = use_kratos();
+  let schema = use_resource(use_reactive!(|schema_id| {
+    let kratos = kratos.to_owned();
+    async move {
+      match schema_id {
+        Some(id) => identity_schema::fetch(&kratos, &id).await,
+        None => None,
+      }
+    }
+  }));
+  use_context_provider(|| TraitsSchema(schema));
+
+  let i18n = use_i18n();
+  let fields = Rc::new(fields(&ui.nodes));
+  let submit = move |evt: FormEvent| {
+    let Some(onsubmit) = onsubmit else {
+      return;
+    };
+    evt.prevent_default();
+    match form_body(&evt, &fields) {
+      Ok(body) => onsubmit.call(body),
+      Err(err) => error!("Failed to read form: {err:?}"),
+    }
+  };
+
+  let onload_triggers = ui
+    .nodes
+    .iter()
+    .filter_map(|n| match &*n.attributes {
+      Input(i) => i.onload_trigger.as_ref().map(webauthn::trigger_name),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
   let default = ui
     .nodes
     .extract_if(.., |n| {
@@ -427,12 +870,18 @@ pub fn FormBuilder(ui: ory_kratos_client_wasm::models::UiContainer) -> Element {
 
   if node_groups.is_empty() {
     rsx! {
+      for trigger in onload_triggers {
+        OnLoadTrigger { trigger }
+      }
       if let Some(messages) = ui.messages {
         for message in messages {
           MessageNode { message }
         }
       }
-      form { action: ui.action.clone(), method: ui.method.clone(),
+      form {
+        action: ui.action.clone(),
+        method: ui.method.clone(),
+        onsubmit: submit,
         div { class: "mt-2",
           fieldset { class: "fieldset",
             NodeBuilder { nodes: default }
@@ -442,25 +891,33 @@ pub fn FormBuilder(ui: ory_kratos_client_wasm::models::UiContainer) -> Element {
     }
   } else {
     rsx! {
+      for trigger in onload_triggers {
+        OnLoadTrigger { trigger }
+      }
       if let Some(messages) = ui.messages {
         for message in messages {
           MessageNode { message }
         }
       }
       for node_group in node_groups {
-        form { action: ui.action.clone(), method: ui.method.clone(),
+        form {
+          action: ui.action.clone(),
+          method: ui.method.clone(),
+          onsubmit: submit.clone(),
           div { class: "mt-2",
             fieldset { class: "fieldset",
               legend { class: "fieldset-legend text-xl",
                 {
-                    match node_group[0].group {
+                    i18n.t(match node_group[0].group {
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Password => "Password",
-                        ory_kratos_client_wasm::models::ui_node::GroupEnum::Oidc => "OIDC",
+                        ory_kratos_client_wasm::models::ui_node::GroupEnum::Oidc => "Social Sign-In",
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Profile => "Profile",
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Code => "Code",
-                        ory_kratos_client_wasm::models::ui_node::GroupEnum::Totp => "TOTP",
+                        ory_kratos_client_wasm::models::ui_node::GroupEnum::Totp => {
+                            "Authenticator App"
+                        }
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::LookupSecret => {
-                            "Recovery"
+                            "Recovery Codes"
                         }
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Webauthn => {
                             "Web Authentication"
@@ -469,11 +926,11 @@ pub fn FormBuilder(ui: ory_kratos_client_wasm::models::UiContainer) -> Element {
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Captcha => "Captcha",
                         ory_kratos_client_wasm::models::ui_node::GroupEnum::Saml => "SAML",
                         _ => "",
-                    }
+                    })
                 }
               }
               NodeBuilder { nodes: default.clone() }
-              NodeBuilder { nodes: node_group }
+              GroupBuilder { nodes: node_group }
             }
           }
         }
@@ -1,12 +1,16 @@
-use crate::{Configuration, Create};
+use crate::i18n::use_i18n;
+use crate::kratos::use_kratos;
 use dioxus::logger::tracing::error;
 use dioxus::prelude::*;
 use ory_kratos_client_wasm::apis::frontend_api::create_browser_logout_flow;
 
 #[component]
 pub fn OryLogOut() -> Element {
-  let create_flow = use_resource(move || async move {
-    create_browser_logout_flow(&Configuration::create(), None, None).await
+  let kratos = use_kratos();
+  let i18n = use_i18n();
+  let create_flow = use_resource(move || {
+    let kratos = kratos.to_owned();
+    async move { create_browser_logout_flow(&kratos, None, None).await }
   });
 
   return match &*create_flow.read() {
@@ -14,7 +18,7 @@ pub fn OryLogOut() -> Element {
       Ok(res) => {
         rsx! {
           li {
-            a { href: res.logout_url.to_owned(), "Log out" }
+            a { href: res.logout_url.to_owned(), {i18n.t("Log out")} }
           }
         }
       }
@@ -22,14 +26,14 @@ pub fn OryLogOut() -> Element {
         error!("{err:#?}");
         rsx! {
           li { class: "menu-disabled",
-            a { href: "", "Log out" }
+            a { href: "", {i18n.t("Log out")} }
           }
         }
       }
     },
     None => rsx! {
       li { class: "menu-disabled",
-        a { href: "", "Log out" }
+        a { href: "", {i18n.t("Log out")} }
       }
     },
   };
@@ -0,0 +1,198 @@
+use super::ory_form_builder::NodeBuilder;
+use crate::branding::use_branding;
+use crate::browser::copy_to_clipboard;
+use crate::i18n::use_i18n;
+use dioxus::logger::tracing::error;
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::models::UiNodeAttributes::{Input, Text};
+use ory_kratos_client_wasm::models::{UiNode, UiText};
+
+/// Shown in place of a used code, whose secret Kratos doesn't send.
+const USED_PLACEHOLDER: &str = "••••••••";
+
+#[derive(Clone, Debug, PartialEq)]
+struct RecoveryCode {
+  code: String,
+  used: bool,
+}
+
+/// Reads the codes from the `lookup_secret_codes` text node, whose context lists one message per
+/// code. Used codes carry a `used_at` timestamp instead of the `secret`, and a "Secret was already
+/// used at ..." sentence as their text.
+fn recovery_codes(text: &UiText) -> Vec<RecoveryCode> {
+  let Some(secrets) = text
+    .context
+    .as_ref()
+    .and_then(|context| context.get("secrets"))
+    .and_then(|secrets| secrets.as_array())
+  else {
+    return Vec::new();
+  };
+
+  secrets
+    .iter()
+    .map(|secret| {
+      let context = secret.get("context");
+      let code = context
+        .and_then(|context| context.get("secret"))
+        .and_then(|code| code.as_str())
+        .unwrap_or_default()
+        .to_owned();
+      let used = context.is_some_and(|context| context.get("used_at").is_some());
+      RecoveryCode { code, used }
+    })
+    .collect()
+}
+
+/// One code per line, for downloading or copying.
+fn codes_text(codes: &[RecoveryCode]) -> String {
+  codes
+    .iter()
+    .filter(|code| !code.used)
+    .map(|code| code.code.as_str())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn print_page() {
+  if let Some(window) = web_sys::window()
+    && let Err(err) = window.print()
+  {
+    error!("Failed to print: {err:?}");
+  }
+}
+
+/// Renders the lookup secret group: the recovery codes grid with download, copy and print
+/// actions, and a confirmation step before regenerating existing codes.
+#[component]
+pub fn LookupSecretNodes(nodes: Vec<UiNode>) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let mut confirming = use_signal(|| false);
+  let mut copied = use_signal(|| false);
+
+  let mut codes = Vec::new();
+  let mut regenerate = None;
+  let mut has_codes = false;
+  let mut rest = Vec::with_capacity(nodes.len());
+
+  for node in nodes {
+    match &*node.attributes {
+      Text(text) if text.id == "lookup_secret_codes" => codes = recovery_codes(&text.text),
+      Input(input) if input.name == "lookup_secret_regenerate" => {
+        let label = node
+          .meta
+          .label
+          .as_ref()
+          .map(|label| i18n.text(label))
+          .unwrap_or_default();
+        regenerate = Some((label, input.disabled));
+      }
+      Input(input)
+        if input.name == "lookup_secret_reveal" || input.name == "lookup_secret_disable" =>
+      {
+        has_codes = true;
+        rest.push(node.to_owned());
+      }
+      _ => rest.push(node.to_owned()),
+    }
+  }
+  has_codes |= !codes.is_empty();
+
+  let text = codes_text(&codes);
+  let download = format!(
+    "data:text/plain;charset=utf-8,{}",
+    String::from(web_sys::js_sys::encode_uri_component(&text))
+  );
+  let copy = move |_: MouseEvent| {
+    let text = text.to_owned();
+    spawn(async move {
+      match copy_to_clipboard(&text).await {
+        Ok(()) => copied.set(true),
+        Err(err) => error!("Failed to copy recovery codes: {err:?}"),
+      }
+    });
+  };
+
+  rsx! {
+    if !codes.is_empty() {
+      p { class: "text-sm my-2",
+        {
+            i18n.t(
+                "Store these codes somewhere safe. Each code can be used once to sign in if you lose access to your other second factors.",
+            )
+        }
+      }
+      div { class: "grid grid-cols-2 gap-2 my-4 p-4 rounded-box bg-base-200 font-mono text-center",
+        for code in codes {
+          if code.used {
+            span { class: "opacity-50",
+              "{USED_PLACEHOLDER} "
+              span { class: "badge badge-ghost badge-sm", {i18n.t("Used")} }
+            }
+          } else {
+            span { {code.code} }
+          }
+        }
+      }
+      div { class: "flex gap-2 print:hidden",
+        a {
+          class: "btn btn-outline flex-1",
+          download: "recovery-codes.txt",
+          href: download,
+          {i18n.t("Download")}
+        }
+        button {
+          class: "btn btn-outline flex-1",
+          r#type: "button",
+          onclick: copy,
+          if copied() {
+            {i18n.t("Copied")}
+          } else {
+            {i18n.t("Copy all")}
+          }
+        }
+        button {
+          class: "btn btn-outline flex-1",
+          r#type: "button",
+          onclick: move |_| print_page(),
+          {i18n.t("Print")}
+        }
+      }
+    }
+    NodeBuilder { nodes: rest }
+    if let Some((label, disabled)) = regenerate {
+      if has_codes && !confirming() {
+        button {
+          class: "{branding.button_class} w-full my-4",
+          disabled,
+          r#type: "button",
+          onclick: move |_| confirming.set(true),
+          {label}
+        }
+      } else {
+        if confirming() {
+          div { role: "alert", class: "alert alert-warning my-2",
+            span { {i18n.t("Regenerating invalidates all of your existing recovery codes.")} }
+          }
+        }
+        button {
+          class: "{branding.button_class} w-full my-4",
+          disabled,
+          name: "lookup_secret_regenerate",
+          r#type: "submit",
+          value: "true",
+          {label}
+        }
+        if confirming() {
+          button {
+            class: "btn btn-ghost w-full",
+            r#type: "button",
+            onclick: move |_| confirming.set(false),
+            {i18n.t("Cancel")}
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,270 @@
+use super::ory_form_builder::NodeBuilder;
+use crate::config::AppConfig;
+use crate::i18n::{I18n, use_i18n};
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::models::UiNode;
+use ory_kratos_client_wasm::models::UiNodeAttributes::Input;
+
+const DEFAULT_BUTTON_LABEL: &str = "Continue with {provider}";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Provider {
+  Google,
+  GitHub,
+  Microsoft,
+  Apple,
+  GitLab,
+  Generic,
+}
+
+impl Provider {
+  /// Recognises a Kratos provider id such as `github` or `google-workspace`.
+  fn from_id(id: &str) -> Self {
+    let id = id.to_lowercase();
+    if id.contains("google") {
+      Provider::Google
+    } else if id.contains("github") {
+      Provider::GitHub
+    } else if id.contains("microsoft") || id.contains("azure") {
+      Provider::Microsoft
+    } else if id.contains("apple") {
+      Provider::Apple
+    } else if id.contains("gitlab") {
+      Provider::GitLab
+    } else {
+      Provider::Generic
+    }
+  }
+
+  fn name(self) -> Option<&'static str> {
+    match self {
+      Provider::Google => Some("Google"),
+      Provider::GitHub => Some("GitHub"),
+      Provider::Microsoft => Some("Microsoft"),
+      Provider::Apple => Some("Apple"),
+      Provider::GitLab => Some("GitLab"),
+      Provider::Generic => None,
+    }
+  }
+
+  /// Brand colours, following daisyUI's login button examples.
+  fn class(self) -> &'static str {
+    match self {
+      Provider::Google => "btn bg-white text-black border-[#e5e5e5] w-full",
+      Provider::GitHub => "btn bg-black text-white border-black w-full",
+      Provider::Microsoft => "btn bg-[#2F2F2F] text-white border-black w-full",
+      Provider::Apple => "btn bg-black text-white border-black w-full",
+      Provider::GitLab => "btn bg-[#FC6D26] text-white border-[#e5591f] w-full",
+      Provider::Generic => "btn btn-neutral w-full",
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+  SignIn,
+  Link,
+  Unlink,
+}
+
+impl Action {
+  /// Name of the submit field Kratos expects the provider id in.
+  fn field(self) -> &'static str {
+    match self {
+      Action::SignIn => "provider",
+      Action::Link => "link",
+      Action::Unlink => "unlink",
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct ProviderButton {
+  action: Action,
+  id: String,
+  name: String,
+  provider: Provider,
+  disabled: bool,
+}
+
+impl ProviderButton {
+  /// Reads a `provider`, `link` or `unlink` submit node, whose value is the provider id.
+  fn from_node(node: &UiNode) -> Option<Self> {
+    let Input(input) = &*node.attributes else {
+      return None;
+    };
+
+    let action = match input.name.as_str() {
+      "provider" => Action::SignIn,
+      "link" => Action::Link,
+      "unlink" => Action::Unlink,
+      _ => return None,
+    };
+
+    let id = input
+      .value
+      .as_ref()
+      .and_then(|value| value.as_ref())
+      .and_then(|value| value.as_str())?
+      .to_owned();
+    let provider = Provider::from_id(&id);
+
+    // Kratos passes the configured provider label as message context.
+    let name = provider
+      .name()
+      .map(str::to_owned)
+      .or_else(|| {
+        node
+          .meta
+          .label
+          .as_ref()
+          .and_then(|label| label.context.as_ref())
+          .and_then(|context| context.get("provider"))
+          .and_then(|provider| provider.as_str())
+          .map(str::to_owned)
+      })
+      .unwrap_or_else(|| id.to_owned());
+
+    Some(ProviderButton {
+      action,
+      id,
+      name,
+      provider,
+      disabled: input.disabled,
+    })
+  }
+
+  fn class(&self) -> &'static str {
+    match self.action {
+      Action::Unlink => "btn btn-error btn-outline w-full",
+      _ => self.provider.class(),
+    }
+  }
+
+  fn label(&self, i18n: &I18n, sign_in_label: &str) -> String {
+    let provider = [("provider", self.name.as_str())];
+    match self.action {
+      Action::SignIn => sign_in_label.replace("{provider}", &self.name),
+      Action::Link => i18n.t_with("Link {provider}", &provider),
+      Action::Unlink => i18n.t_with("Unlink {provider}", &provider),
+    }
+  }
+}
+
+#[component]
+fn ProviderIcon(provider: Provider) -> Element {
+  match provider {
+    Provider::Google => rsx! {
+      svg { class: "size-4", view_box: "0 0 18 18", "aria-hidden": "true",
+        path {
+          fill: "#EA4335",
+          d: "M9 3.48c1.69 0 2.83.73 3.48 1.34l2.54-2.48C13.46.89 11.43 0 9 0 5.48 0 2.44 2.02.96 4.96l2.91 2.26C4.6 5.05 6.62 3.48 9 3.48z",
+        }
+        path {
+          fill: "#4285F4",
+          d: "M17.64 9.2c0-.74-.06-1.28-.19-1.84H9v3.34h4.96c-.1.83-.64 2.08-1.84 2.92l2.84 2.2c1.7-1.57 2.68-3.88 2.68-6.62z",
+        }
+        path {
+          fill: "#FBBC05",
+          d: "M3.88 10.78A5.54 5.54 0 0 1 3.58 9c0-.62.11-1.22.29-1.78L.96 4.96A9.008 9.008 0 0 0 0 9c0 1.45.35 2.82.96 4.04l2.92-2.26z",
+        }
+        path {
+          fill: "#34A853",
+          d: "M9 18c2.43 0 4.47-.8 5.96-2.18l-2.84-2.2c-.76.53-1.78.9-3.12.9-2.38 0-4.4-1.57-5.12-3.74L.97 13.04C2.45 15.98 5.48 18 9 18z",
+        }
+      }
+    },
+    Provider::GitHub => rsx! {
+      svg { class: "size-4", view_box: "0 0 16 16", "aria-hidden": "true",
+        path {
+          fill: "currentColor",
+          d: "M8 0C3.58 0 0 3.58 0 8c0 3.54 2.29 6.53 5.47 7.59.4.07.55-.17.55-.38 0-.19-.01-.82-.01-1.49-2.01.37-2.53-.49-2.69-.94-.09-.23-.48-.94-.82-1.13-.28-.15-.68-.52-.01-.53.63-.01 1.08.58 1.23.82.72 1.21 1.87.87 2.33.66.07-.52.28-.87.51-1.07-1.78-.2-3.64-.89-3.64-3.95 0-.87.31-1.59.82-2.15-.08-.2-.36-1.02.08-2.12 0 0 .67-.21 2.2.82.64-.18 1.32-.27 2-.27.68 0 1.36.09 2 .27 1.53-1.04 2.2-.82 2.2-.82.44 1.1.16 1.92.08 2.12.51.56.82 1.27.82 2.15 0 3.07-1.87 3.75-3.65 3.95.29.25.54.73.54 1.48 0 1.07-.01 1.93-.01 2.2 0 .21.15.46.55.38A8.01 8.01 0 0 0 16 8c0-4.42-3.58-8-8-8z",
+        }
+      }
+    },
+    Provider::Microsoft => rsx! {
+      svg { class: "size-4", view_box: "0 0 16 16", "aria-hidden": "true",
+        rect { fill: "#F25022", x: 0, y: 0, width: 7.5, height: 7.5 }
+        rect { fill: "#7FBA00", x: 8.5, y: 0, width: 7.5, height: 7.5 }
+        rect { fill: "#00A4EF", x: 0, y: 8.5, width: 7.5, height: 7.5 }
+        rect { fill: "#FFB900", x: 8.5, y: 8.5, width: 7.5, height: 7.5 }
+      }
+    },
+    Provider::Apple => rsx! {
+      svg { class: "size-4", view_box: "0 0 24 24", "aria-hidden": "true",
+        path {
+          fill: "currentColor",
+          d: "M16.5 12.6c0-2.3 1.9-3.4 2-3.5-1.1-1.6-2.8-1.8-3.4-1.8-1.4-.1-2.8.9-3.5.9-.7 0-1.8-.8-3-.8-1.5 0-3 .9-3.8 2.3-1.6 2.8-.4 7 1.2 9.3.8 1.1 1.7 2.4 2.9 2.3 1.2 0 1.6-.7 3-.7s1.8.7 3 .7c1.3 0 2.1-1.1 2.8-2.3.9-1.3 1.3-2.6 1.3-2.6s-2.5-1-2.5-3.8zM14.2 5.8c.6-.8 1.1-1.8 1-2.8-.9 0-2 .6-2.7 1.4-.6.7-1.1 1.7-1 2.7 1 .1 2-.5 2.7-1.3z",
+        }
+      }
+    },
+    Provider::GitLab => rsx! {
+      svg { class: "size-4", view_box: "0 0 24 24", "aria-hidden": "true",
+        path { fill: "currentColor", d: "M12 22 2 14.5 4.3 3 7.2 11h9.6l2.9-8L22 14.5z" }
+      }
+    },
+    Provider::Generic => rsx! {
+      svg {
+        class: "size-4",
+        view_box: "0 0 24 24",
+        fill: "none",
+        stroke: "currentColor",
+        stroke_width: "2",
+        "aria-hidden": "true",
+        path {
+          stroke_linecap: "round",
+          stroke_linejoin: "round",
+          d: "M15 3h4a2 2 0 0 1 2 2v14a2 2 0 0 1-2 2h-4M10 17l5-5-5-5M15 12H3",
+        }
+      }
+    },
+  }
+}
+
+/// Renders the OIDC group: branded social sign-in buttons in the configured order, and the link
+/// and unlink buttons of the settings flow.
+#[component]
+pub fn OidcNodes(nodes: Vec<UiNode>) -> Element {
+  let config = use_context::<AppConfig>();
+  let i18n = use_i18n();
+
+  let mut buttons = Vec::new();
+  let mut rest = Vec::with_capacity(nodes.len());
+  for node in nodes {
+    match ProviderButton::from_node(&node) {
+      Some(button) => buttons.push(button),
+      None => rest.push(node),
+    }
+  }
+
+  // Stable, so unlisted providers keep Kratos' order after the listed ones.
+  buttons.sort_by_key(|button| {
+    config
+      .oidc_provider_order
+      .iter()
+      .position(|id| id.eq_ignore_ascii_case(&button.id))
+      .unwrap_or(usize::MAX)
+  });
+
+  let sign_in_label = config
+    .oidc_button_label
+    .unwrap_or_else(|| i18n.t(DEFAULT_BUTTON_LABEL));
+
+  rsx! {
+    NodeBuilder { nodes: rest }
+    div { class: "flex flex-col gap-2 my-4",
+      for button in buttons {
+        button {
+          key: "{button.action:?}-{button.id}",
+          class: button.class(),
+          disabled: button.disabled,
+          name: button.action.field(),
+          r#type: "submit",
+          value: button.id.to_owned(),
+          ProviderIcon { provider: button.provider }
+          {button.label(&i18n, &sign_in_label)}
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,143 @@
+use crate::branding::use_branding;
+use crate::config::AppConfig;
+use crate::i18n::use_i18n;
+use crate::password::{score, similar};
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::models::ui_node_input_attributes::AutocompleteEnum;
+use ory_kratos_client_wasm::models::{UiNodeInputAttributes, UiText};
+use web_sys::js_sys::Reflect;
+
+/// Fields the user's identifier may be entered in, compared against new passwords.
+const IDENTIFIER_FIELDS: [&str; 4] = [
+  "identifier",
+  "traits.email",
+  "traits.username",
+  "traits.phone",
+];
+
+const STRENGTHS: [(&str, &str); 5] = [
+  ("Very weak", "progress-error"),
+  ("Weak", "progress-error"),
+  ("Fair", "progress-warning"),
+  ("Strong", "progress-success"),
+  ("Very strong", "progress-success"),
+];
+
+/// Values of the identifier fields on the page.
+fn identifiers() -> Vec<String> {
+  let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+    return Vec::new();
+  };
+
+  IDENTIFIER_FIELDS
+    .into_iter()
+    .filter_map(|name| {
+      let field = document
+        .query_selector(&format!("input[name=\"{name}\"]"))
+        .ok()??;
+      Reflect::get(&field, &"value".into()).ok()?.as_string()
+    })
+    .filter(|value| !value.is_empty())
+    .collect()
+}
+
+#[component]
+fn Check(met: bool, children: Element) -> Element {
+  rsx! {
+    li { class: if met { "text-success" } else { "text-error" },
+      if met {
+        "✓ "
+      } else {
+        "✗ "
+      }
+      {children}
+    }
+  }
+}
+
+/// A password input with a show/hide toggle. New passwords get a strength meter and a checklist
+/// of the configured [`PasswordPolicy`](crate::config::PasswordPolicy).
+#[component]
+pub(super) fn PasswordNode(meta: Option<Box<UiText>>, attrs: UiNodeInputAttributes) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let policy = use_context::<AppConfig>().password_policy;
+
+  let mut password = use_signal(String::new);
+  let mut visible = use_signal(|| false);
+  let mut user_inputs = use_signal(Vec::<String>::new);
+
+  // Signing in checks the current password, which the policy doesn't apply to.
+  let new_password = attrs.autocomplete == Some(AutocompleteEnum::NewPassword);
+  let label = meta
+    .as_ref()
+    .map(|label| i18n.text(label))
+    .unwrap_or_default();
+  let id = meta.as_ref().map(|label| label.id.to_string());
+
+  let value = password();
+  let inputs = user_inputs();
+  let inputs = inputs.iter().map(String::as_str).collect::<Vec<_>>();
+  let strength_score = score(&value, &inputs);
+  let (strength, strength_class) = STRENGTHS[strength_score as usize];
+  let long_enough = value.chars().count() >= policy.min_length;
+  let unlike_identifier = !inputs.iter().any(|input| similar(&value, input));
+
+  rsx! {
+    div { class: "my-4",
+      label { class: "label mb-1", r#for: id.to_owned(), {label.to_owned()} }
+      div { class: "join w-full",
+        input {
+          class: "{branding.input_class} join-item w-full",
+          id,
+          name: attrs.name,
+          r#type: if visible() { "text" } else { "password" },
+          required: attrs.required.unwrap_or_default(),
+          disabled: attrs.disabled,
+          autocomplete: if new_password { "new-password" } else { "current-password" },
+          placeholder: label,
+          oninput: move |evt: FormEvent| {
+              password.set(evt.value());
+              if new_password {
+                  user_inputs.set(identifiers());
+              }
+          },
+        }
+        button {
+          class: "btn join-item",
+          r#type: "button",
+          aria_pressed: visible(),
+          onclick: move |_| visible.toggle(),
+          if visible() {
+            {i18n.t("Hide")}
+          } else {
+            {i18n.t("Show")}
+          }
+        }
+      }
+      if new_password && !value.is_empty() {
+        progress {
+          class: "progress {strength_class} w-full mt-2",
+          value: strength_score as i64 + 1,
+          max: STRENGTHS.len() as i64,
+        }
+        p { class: "text-sm", {i18n.t(strength)} }
+        ul { class: "text-sm my-2",
+          Check { met: long_enough,
+            {i18n.t_with("At least {count} characters", &[("count", &policy.min_length.to_string())])}
+          }
+          if policy.identifier_similarity {
+            Check { met: unlike_identifier,
+              {i18n.t("Not similar to your email address or username")}
+            }
+          }
+          if policy.haveibeenpwned {
+            li { class: "opacity-60",
+              {i18n.t("Checked against known data breaches when you submit")}
+            }
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,186 @@
+use super::ory_form_builder::NodeBuilder;
+use crate::branding::use_branding;
+use crate::browser::copy_to_clipboard;
+use crate::i18n::use_i18n;
+use dioxus::logger::tracing::error;
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::models::UiNode;
+use ory_kratos_client_wasm::models::UiNodeAttributes::{Img, Input, Text};
+use std::rc::Rc;
+
+/// Digits in a TOTP code.
+const CODE_LENGTH: usize = 6;
+
+/// Applies `value`, the content of digit box `index` after an input event, to `digits`.
+///
+/// Handles typing over an existing digit as well as pasting or autofilling a whole code.
+/// Returns the index of the box to focus next.
+fn fill_digits(digits: &mut [String], index: usize, value: &str) -> usize {
+  let previous = digits[index].as_str();
+  let value = if value.chars().count() > 1 && !previous.is_empty() {
+    value
+      .strip_prefix(previous)
+      .or_else(|| value.strip_suffix(previous))
+      .unwrap_or(value)
+  } else {
+    value
+  };
+
+  let typed = value
+    .chars()
+    .filter(char::is_ascii_digit)
+    .map(String::from)
+    .collect::<Vec<_>>();
+
+  if typed.is_empty() {
+    digits[index].clear();
+    return index;
+  }
+
+  let count = typed.len().min(digits.len() - index);
+  for (offset, digit) in typed.into_iter().take(count).enumerate() {
+    digits[index + offset] = digit;
+  }
+
+  (index + count).min(digits.len() - 1)
+}
+
+/// One box per digit, submitted as a single `name` field.
+#[component]
+fn CodeInput(name: String, disabled: bool) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let mut digits = use_signal(|| vec![String::new(); CODE_LENGTH]);
+  let mut boxes = use_signal(|| vec![None::<Rc<MountedData>>; CODE_LENGTH]);
+
+  let focus = move |index: usize| {
+    if let Some(mounted) = boxes.read()[index].to_owned() {
+      spawn(async move {
+        if let Err(err) = mounted.set_focus(true).await {
+          error!("{err:?}");
+        }
+      });
+    }
+  };
+
+  rsx! {
+    div { class: "flex gap-2 my-2 justify-center",
+      for index in 0..CODE_LENGTH {
+        input {
+          key: "{index}",
+          class: "{branding.input_class} w-12 text-center text-xl font-mono",
+          aria_label: i18n.t_with("Digit {index}", &[("index", &(index + 1).to_string())]),
+          autocomplete: if index == 0 { "one-time-code" } else { "off" },
+          disabled,
+          inputmode: "numeric",
+          r#type: "text",
+          value: digits.read()[index].to_owned(),
+          onmounted: move |evt| {
+              boxes.write()[index] = Some(evt.data());
+          },
+          oninput: move |evt| {
+              let next = fill_digits(&mut digits.write(), index, &evt.value());
+              focus(next);
+          },
+          onkeydown: move |evt| {
+              if evt.key() == Key::Backspace && index > 0 && digits.read()[index].is_empty() {
+                  focus(index - 1);
+              }
+          },
+        }
+      }
+    }
+    input { name, r#type: "hidden", value: digits.read().concat() }
+  }
+}
+
+/// Renders the TOTP group: QR code, copyable secret key, code input and unlink action.
+#[component]
+pub fn TotpNodes(nodes: Vec<UiNode>) -> Element {
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let mut copied = use_signal(|| false);
+
+  let mut qr = None;
+  let mut secret = None;
+  let mut code = None;
+  let mut unlink = None;
+  let mut rest = Vec::with_capacity(nodes.len());
+
+  for node in nodes {
+    let label = node
+      .meta
+      .label
+      .as_ref()
+      .map(|label| i18n.text(label))
+      .unwrap_or_default();
+
+    match &*node.attributes {
+      Img(img) if img.id == "totp_qr" => qr = Some(img.src.to_owned()),
+      Text(text) if text.id == "totp_secret_key" => secret = Some(text.text.text.to_owned()),
+      Input(input) if input.name == "totp_code" => code = Some((label, input.disabled)),
+      Input(input) if input.name == "totp_unlink" => unlink = Some((label, input.disabled)),
+      _ => rest.push(node.to_owned()),
+    }
+  }
+
+  let copy_secret = secret.to_owned();
+  let copy = move |_: MouseEvent| {
+    let Some(secret) = copy_secret.to_owned() else {
+      return;
+    };
+    spawn(async move {
+      match copy_to_clipboard(&secret).await {
+        Ok(()) => copied.set(true),
+        Err(err) => error!("Failed to copy secret key: {err:?}"),
+      }
+    });
+  };
+
+  rsx! {
+    if let Some(src) = qr {
+      p { class: "text-sm my-2",
+        {i18n.t("Scan the QR code with your authenticator app, or enter the secret key manually.")}
+      }
+      img {
+        class: "mx-auto my-4 bg-white p-2 rounded-box",
+        alt: i18n.t("Authenticator app QR code"),
+        height: 256,
+        src,
+        width: 256,
+      }
+    }
+    if let Some(secret) = secret {
+      label { class: "label", {i18n.t("Secret key")} }
+      div { class: "join w-full my-2",
+        input {
+          class: "{branding.input_class} join-item w-full font-mono",
+          readonly: true,
+          value: secret,
+        }
+        button { class: "btn join-item", r#type: "button", onclick: copy,
+          if copied() {
+            {i18n.t("Copied")}
+          } else {
+            {i18n.t("Copy")}
+          }
+        }
+      }
+    }
+    if let Some((label, disabled)) = code {
+      label { class: "label mt-4", {label} }
+      CodeInput { name: "totp_code", disabled }
+    }
+    NodeBuilder { nodes: rest }
+    if let Some((label, disabled)) = unlink {
+      button {
+        class: "btn btn-error btn-outline w-full my-4",
+        disabled,
+        name: "totp_unlink",
+        r#type: "submit",
+        value: "true",
+        {label}
+      }
+    }
+  }
+}
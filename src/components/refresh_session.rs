@@ -0,0 +1,21 @@
+use crate::Route;
+use crate::session::use_session;
+use dioxus::prelude::*;
+
+/// Landing page for Kratos' `default_browser_return_url`, e.g. after signing in or out. Checks
+/// the session again, then continues home.
+#[component]
+pub fn RefreshSession() -> Element {
+  let session = use_session();
+  let nav = navigator();
+
+  use_future(move || {
+    let session = session.to_owned();
+    async move {
+      session.refresh().await;
+      nav.replace(Route::Home {});
+    }
+  });
+
+  rsx! {}
+}
@@ -0,0 +1,54 @@
+use crate::browser::current_url;
+use crate::continue_with::Next;
+use crate::session::{SessionState, use_session};
+use crate::views::LoginParams;
+use crate::{Access, Route};
+use dioxus::prelude::*;
+
+/// Layout that enforces [`Route::access`]: protected routes send signed out users to sign in and
+/// back, or to the second factor if their session is not strong enough, and anonymous-only routes
+/// send signed in users home, or on to their `return_to` if it is a route of the app.
+#[component]
+pub fn RouteGuard() -> Element {
+  let route = use_route::<Route>();
+  let session = use_session();
+
+  let redirect = match (route.access(), session.state()) {
+    (Access::Any, _) => None,
+    (_, SessionState::Loading) => return rsx! {},
+    (Access::Session(_), SessionState::SignedOut) => Some(
+      LoginParams {
+        return_to: current_url(),
+        ..Default::default()
+      }
+      .into(),
+    ),
+    (Access::Session(_), SessionState::SecondFactorRequired) => Some(Route::SecondFactor {
+      return_to: current_url(),
+    }),
+    (Access::Session(aal), SessionState::SignedIn(_)) => session
+      .aal()
+      .is_none_or(|current| current < aal)
+      .then(|| Route::SecondFactor {
+        return_to: current_url(),
+      }),
+    // Signed in users skip ahead to where signing in would have taken them. Other sites aren't
+    // followed, only Kratos checks them against `selfservice.allowed_return_urls`.
+    (Access::Anonymous, _) if session.is_authenticated() => {
+      match route.return_to().map(Next::url) {
+        Some(Next::Route(route)) => Some(route),
+        _ => Some(Route::Home {}),
+      }
+    }
+    (Access::Anonymous, _) => None,
+  };
+
+  if let Some(to) = redirect {
+    navigator().replace(to);
+    return rsx! {};
+  }
+
+  rsx! {
+    Outlet::<Route> {}
+  }
+}
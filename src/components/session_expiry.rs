@@ -0,0 +1,76 @@
+use crate::Route;
+use crate::branding::use_branding;
+use crate::browser::{current_url, sleep};
+use crate::config::AppConfig;
+use crate::i18n::use_i18n;
+use crate::session::use_session;
+use crate::views::LoginParams;
+use chrono::{DateTime, Duration, Utc};
+use dioxus::prelude::*;
+
+/// Warns before the session expires, offering to extend it by signing in again, and signs the
+/// user out locally once it has.
+#[component]
+pub fn SessionExpiry() -> Element {
+  let session = use_session();
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let warning = Duration::minutes(use_context::<AppConfig>().session_warning_minutes);
+
+  let mut now = use_signal(Utc::now);
+  // Expiry the user dismissed the warning for, a new session warns again.
+  let mut dismissed = use_signal(|| None::<DateTime<Utc>>);
+
+  let tick_session = session.to_owned();
+  use_future(move || {
+    let session = tick_session.to_owned();
+    async move {
+      loop {
+        sleep(1000).await;
+        now.set(Utc::now());
+        if session
+          .expires_at()
+          .is_some_and(|expires_at| expires_at <= Utc::now())
+        {
+          session.expire();
+        }
+      }
+    }
+  });
+
+  let Some(expires_at) = session.expires_at().filter(|_| session.is_authenticated()) else {
+    return rsx! {};
+  };
+
+  let remaining = expires_at - now();
+  if remaining > warning || dismissed() == Some(expires_at) {
+    return rsx! {};
+  }
+
+  let seconds = remaining.num_seconds().max(0);
+  let countdown = format!("{}:{:02}", seconds / 60, seconds % 60);
+  let extend = LoginParams {
+    return_to: current_url(),
+    refresh: true,
+    ..Default::default()
+  };
+
+  rsx! {
+    div { class: "modal modal-open", role: "dialog",
+      div { class: "modal-box",
+        h3 { class: "text-lg font-bold", {i18n.t("Your session is about to expire")} }
+        p { class: "py-4",
+          {i18n.t_with("You will be signed out in {countdown}.", &[("countdown", &countdown)])}
+        }
+        div { class: "modal-action",
+          button {
+            class: "btn btn-ghost",
+            onclick: move |_| dismissed.set(Some(expires_at)),
+            {i18n.t("Dismiss")}
+          }
+          Link { class: branding.button_class, to: Route::from(extend), {i18n.t("Extend session")} }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use crate::branding::{ColorMode, use_color_scheme};
+use crate::i18n::use_i18n;
+use dioxus::prelude::*;
+
+/// Switches between the light, dark and system color mode, which is remembered for the user's
+/// next visit.
+#[component]
+pub fn ThemeSwitcher() -> Element {
+  let scheme = use_color_scheme();
+  let i18n = use_i18n();
+  let current = scheme.mode();
+
+  rsx! {
+    div { class: "join w-full", role: "group", aria_label: i18n.t("Theme"),
+      for mode in ColorMode::ALL {
+        button {
+          key: "{mode:?}",
+          class: "btn btn-sm join-item flex-1",
+          class: if mode == current { "btn-active" },
+          aria_pressed: mode == current,
+          onclick: move |_| scheme.set_mode(mode),
+          match mode {
+              ColorMode::Light => i18n.t("Light"),
+              ColorMode::Dark => i18n.t("Dark"),
+              ColorMode::Auto => i18n.t("System"),
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,24 @@
+use crate::i18n::use_i18n;
+use crate::time::{local, parse, relative};
+use dioxus::prelude::*;
+
+/// An RFC 3339 timestamp from Kratos as a local time with a relative hint, e.g.
+/// `10/18/2026, 2:05:00 PM (in 23 hours)`, in the selected locale. Shown as is if it doesn't
+/// parse.
+#[component]
+pub fn Timestamp(value: String) -> Element {
+  let locale = use_i18n().locale();
+  let Some(at) = parse(&value) else {
+    return rsx! { "{value}" };
+  };
+
+  let local = local(at, locale);
+  let relative = relative(at, locale);
+
+  rsx! {
+    time { datetime: value,
+      "{local} "
+      span { class: "opacity-60", "({relative})" }
+    }
+  }
+}
@@ -0,0 +1,287 @@
+use crate::branding::{Branding, FooterLink};
+use crate::browser::{FetchError, from_js_value, get_json};
+use dioxus::logger::tracing::{debug, error};
+use serde::Deserialize;
+use web_sys::js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+/// Kratos public API used when no runtime configuration is provided.
+const DEFAULT_KRATOS_URL: &str = "http://127.0.0.1:4433";
+/// Global object checked first, e.g. `window.__KRATOS_CONFIG__ = { kratos_url: "..." }`.
+const CONFIG_GLOBAL: &str = "__KRATOS_CONFIG__";
+/// File fetched from the site root when neither the global nor meta tags are set.
+const CONFIG_PATH: &str = "/config.json";
+/// Minutes before the session expires that the user is warned.
+const DEFAULT_SESSION_WARNING_MINUTES: i64 = 5;
+/// Kratos' default `min_password_length`.
+const DEFAULT_PASSWORD_MIN_LENGTH: usize = 8;
+/// Kratos' default `identity.default_schema_id`.
+const DEFAULT_IDENTITY_SCHEMA_ID: &str = "default";
+
+/// Runtime configuration, resolved once at startup and provided as context.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AppConfig {
+  pub kratos_url: String,
+  pub user_agent: Option<String>,
+  /// Backend endpoint that reads and answers Ory Hydra consent requests, see `views::consent`.
+  pub consent_endpoint: Option<String>,
+  /// Social sign-in provider ids in display order, unlisted providers follow in Kratos' order.
+  pub oidc_provider_order: Vec<String>,
+  /// Social sign-in button text, `{provider}` is replaced with the provider's name.
+  pub oidc_button_label: Option<String>,
+  /// Minutes before the session expires that the user is offered to extend it.
+  pub session_warning_minutes: i64,
+  pub branding: Branding,
+  pub home: HomeContent,
+  pub password_policy: PasswordPolicy,
+  /// Identity schema new identities are registered with, whose traits the sign up form follows.
+  pub identity_schema_id: String,
+}
+
+/// Kratos' password policy, `selfservice.methods.password.config`, explained next to new
+/// passwords. Kratos enforces it, so the UI only hints at it and never blocks a password.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PasswordPolicy {
+  pub min_length: usize,
+  /// Whether Kratos rejects passwords found in data breaches, `haveibeenpwned_enabled`.
+  pub haveibeenpwned: bool,
+  /// Whether Kratos rejects passwords similar to the identifier,
+  /// `identifier_similarity_check_enabled`.
+  pub identifier_similarity: bool,
+}
+
+impl Default for PasswordPolicy {
+  fn default() -> Self {
+    PasswordPolicy {
+      min_length: DEFAULT_PASSWORD_MIN_LENGTH,
+      haveibeenpwned: true,
+      identifier_similarity: true,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Section {
+  pub heading: Option<String>,
+  /// Paragraphs, separated by blank lines.
+  pub body: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Card {
+  pub title: String,
+  pub description: String,
+  pub href: String,
+}
+
+/// Content of the home page, which defaults to Ory's introduction and documentation links.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HomeContent {
+  /// `{product}` is replaced with the product name.
+  pub title: String,
+  pub sections: Vec<Section>,
+  pub cards: Vec<Card>,
+  /// Application the home page sends signed in users to instead, signed out users sign in first
+  /// and are returned there.
+  pub app_url: Option<String>,
+}
+
+impl Default for HomeContent {
+  fn default() -> Self {
+    let section = |heading: Option<&str>, body: &str| Section {
+      heading: heading.map(str::to_owned),
+      body: body.to_owned(),
+    };
+    let card = |title: &str, description: &str, href: &str| Card {
+      title: title.to_owned(),
+      description: description.to_owned(),
+      href: href.to_owned(),
+    };
+
+    HomeContent {
+      title: "Welcome to the {product} Account Experience!".to_owned(),
+      sections: vec![
+        section(
+          None,
+          "Let your customers sign up, log in and manage their account using Ory's standard experience. Here you can preview, test and learn to integrate it into your application.",
+        ),
+        section(
+          Some("Core concepts"),
+          "Here are some useful documentation pieces that help you get started right away.",
+        ),
+      ],
+      cards: vec![
+        card(
+          "Getting Started",
+          "Jump start your project and complete the quickstart tutorial to get a broader overview of Ory Network.",
+          "https://www.ory.sh/docs/getting-started/integrate-auth/expressjs",
+        ),
+        card(
+          "User flows",
+          "Implement flows that users perform themselves as opposed to administrative intervention.",
+          "https://www.ory.sh/docs/kratos/self-service",
+        ),
+        card(
+          "Identities 101",
+          "Every identity can have its own model - get to know the ins and outs of Identity Schemas.",
+          "https://www.ory.sh/docs/kratos/manage-identities/identity-schema",
+        ),
+        card(
+          "Sessions",
+          "Ory Network manages sessions for you - get to know how sessions work.",
+          "https://www.ory.sh/docs/kratos/session-management/overview",
+        ),
+        card(
+          "Custom UI",
+          "Implementing these pages in your language and framework of choice is straightforward using our SDKs.",
+          "https://www.ory.sh/docs/kratos/bring-your-own-ui/configure-ory-to-use-your-ui",
+        ),
+      ],
+      app_url: None,
+    }
+  }
+}
+
+impl Default for AppConfig {
+  fn default() -> Self {
+    AppConfig {
+      kratos_url: DEFAULT_KRATOS_URL.to_owned(),
+      user_agent: None,
+      consent_endpoint: None,
+      oidc_provider_order: Vec::new(),
+      oidc_button_label: None,
+      session_warning_minutes: DEFAULT_SESSION_WARNING_MINUTES,
+      branding: Branding::default(),
+      home: HomeContent::default(),
+      password_policy: PasswordPolicy::default(),
+      identity_schema_id: DEFAULT_IDENTITY_SCHEMA_ID.to_owned(),
+    }
+  }
+}
+
+impl AppConfig {
+  /// Resolves the configuration from, in order of precedence, `window.__KRATOS_CONFIG__`,
+  /// `<meta name="kratos-*">` tags, and `/config.json`.
+  /// Falls back to [`AppConfig::default`] if none are present.
+  pub async fn load() -> AppConfig {
+    let config = match from_global().or_else(from_meta) {
+      Some(config) => config,
+      None => from_file().await.unwrap_or_default(),
+    };
+
+    debug!("{config:?}");
+    config
+  }
+}
+
+fn from_js(value: JsValue) -> Option<AppConfig> {
+  if value.is_undefined() || value.is_null() {
+    return None;
+  }
+
+  match from_js_value(&value) {
+    Ok(config) => Some(config),
+    Err(err) => {
+      error!("Invalid runtime configuration: {err}");
+      None
+    }
+  }
+}
+
+fn from_global() -> Option<AppConfig> {
+  let window = web_sys::window()?;
+  from_js(Reflect::get(&window, &JsValue::from_str(CONFIG_GLOBAL)).ok()?)
+}
+
+fn meta_content(document: &web_sys::Document, name: &str) -> Option<String> {
+  document
+    .query_selector(&format!("meta[name=\"{name}\"]"))
+    .ok()??
+    .get_attribute("content")
+}
+
+fn from_meta() -> Option<AppConfig> {
+  let document = web_sys::window()?.document()?;
+  let kratos_url = meta_content(&document, "kratos-url")?;
+
+  Some(AppConfig {
+    kratos_url,
+    user_agent: meta_content(&document, "kratos-user-agent"),
+    consent_endpoint: meta_content(&document, "kratos-consent-endpoint"),
+    oidc_provider_order: meta_content(&document, "kratos-oidc-provider-order")
+      .map(|order| {
+        order
+          .split(',')
+          .map(|id| id.trim().to_owned())
+          .filter(|id| !id.is_empty())
+          .collect()
+      })
+      .unwrap_or_default(),
+    oidc_button_label: meta_content(&document, "kratos-oidc-button-label"),
+    session_warning_minutes: meta_content(&document, "kratos-session-warning-minutes")
+      .and_then(|minutes| minutes.parse().ok())
+      .unwrap_or(DEFAULT_SESSION_WARNING_MINUTES),
+    branding: branding_from_meta(&document),
+    home: HomeContent {
+      app_url: meta_content(&document, "kratos-app-url"),
+      ..Default::default()
+    },
+    password_policy: PasswordPolicy {
+      min_length: meta_content(&document, "kratos-password-min-length")
+        .and_then(|length| length.parse().ok())
+        .unwrap_or(DEFAULT_PASSWORD_MIN_LENGTH),
+      haveibeenpwned: meta_content(&document, "kratos-password-haveibeenpwned")
+        .is_none_or(|enabled| enabled != "false"),
+      identifier_similarity: meta_content(&document, "kratos-password-identifier-similarity")
+        .is_none_or(|enabled| enabled != "false"),
+    },
+    identity_schema_id: meta_content(&document, "kratos-identity-schema-id")
+      .unwrap_or_else(|| DEFAULT_IDENTITY_SCHEMA_ID.to_owned()),
+  })
+}
+
+fn branding_from_meta(document: &web_sys::Document) -> Branding {
+  let default = Branding::default();
+  Branding {
+    product_name: meta_content(document, "kratos-product-name").unwrap_or(default.product_name),
+    logo_url: meta_content(document, "kratos-logo-url"),
+    primary_color: meta_content(document, "kratos-primary-color"),
+    light_theme: meta_content(document, "kratos-light-theme").unwrap_or(default.light_theme),
+    dark_theme: meta_content(document, "kratos-dark-theme").unwrap_or(default.dark_theme),
+    color_mode: meta_content(document, "kratos-color-mode")
+      .and_then(|mode| mode.parse().ok())
+      .unwrap_or(default.color_mode),
+    // `Label=https://...` pairs, separated by commas.
+    footer_links: meta_content(document, "kratos-footer-links")
+      .map(|links| {
+        links
+          .split(',')
+          .filter_map(|link| {
+            let (label, href) = link.split_once('=')?;
+            Some(FooterLink {
+              label: label.trim().to_owned(),
+              href: href.trim().to_owned(),
+            })
+          })
+          .collect()
+      })
+      .unwrap_or_default(),
+    button_class: meta_content(document, "kratos-button-class").unwrap_or(default.button_class),
+    input_class: meta_content(document, "kratos-input-class").unwrap_or(default.input_class),
+  }
+}
+
+async fn from_file() -> Option<AppConfig> {
+  match get_json(CONFIG_PATH).await {
+    Ok(config) => Some(config),
+    Err(err @ FetchError::Json(_)) => {
+      error!("Invalid runtime configuration: {err}");
+      None
+    }
+    // A missing file is not an error, the remaining defaults apply.
+    Err(_) => None,
+  }
+}
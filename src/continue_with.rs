@@ -0,0 +1,66 @@
+use crate::Route;
+use crate::browser::redirect;
+use dioxus::router::Navigator;
+use ory_kratos_client_wasm::models::ContinueWith;
+
+/// Where to go after a flow was submitted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Next {
+  Route(Route),
+  Url(String),
+}
+
+impl Next {
+  /// A URL Kratos sent the browser to, kept within the app if it points at one of its routes.
+  pub fn url(url: &str) -> Next {
+    let origin = web_sys::window()
+      .and_then(|window| window.location().origin().ok())
+      .unwrap_or_default();
+
+    if !origin.is_empty()
+      && let Some(path) = url.strip_prefix(&origin)
+      && let Ok(route) = path.parse::<Route>()
+      && !matches!(route, Route::PageNotFound { .. })
+    {
+      return Next::Route(route);
+    }
+
+    Next::Url(url.to_owned())
+  }
+
+  pub fn go(self, nav: Navigator) {
+    match self {
+      Next::Route(route) => {
+        nav.push(route);
+      }
+      Next::Url(url) => redirect(&url),
+    }
+  }
+}
+
+/// The first of Kratos' `continue_with` actions a browser can act on, e.g. the verification flow
+/// started by the `show_verification_ui` hook after registration.
+///
+/// UI actions take precedence over `redirect_browser_to`, so the redirect isn't followed before
+/// the user has seen them. `set_ory_session_token` only applies to native apps and is ignored.
+pub fn continue_with(items: &[ContinueWith]) -> Option<Next> {
+  let ui = items.iter().find_map(|item| match item {
+    ContinueWith::ShowVerificationUi(action) => Some(Route::VerificationFlow {
+      flow: action.flow.id.to_owned(),
+    }),
+    ContinueWith::ShowSettingsUi(action) => Some(Route::SettingsFlow {
+      flow: action.flow.id.to_owned(),
+    }),
+    ContinueWith::ShowRecoveryUi(action) => Some(Route::RecoveryFlow {
+      flow: action.flow.id.to_owned(),
+    }),
+    _ => None,
+  });
+
+  ui.map(Next::Route).or_else(|| {
+    items.iter().find_map(|item| match item {
+      ContinueWith::RedirectBrowserTo(action) => Some(Next::url(&action.redirect_browser_to)),
+      _ => None,
+    })
+  })
+}
@@ -0,0 +1,60 @@
+use crate::browser::{FetchError, get_json, post_json};
+use serde::{Deserialize, Serialize};
+
+/// Subset of Ory Hydra's `OAuth2Client` shown on the consent screen.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OAuth2Client {
+  pub client_id: Option<String>,
+  pub client_name: Option<String>,
+  pub client_uri: Option<String>,
+  pub logo_uri: Option<String>,
+  pub policy_uri: Option<String>,
+  pub tos_uri: Option<String>,
+}
+
+/// Subset of Ory Hydra's `OAuth2ConsentRequest`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ConsentRequest {
+  pub challenge: String,
+  pub client: Option<OAuth2Client>,
+  pub requested_access_token_audience: Vec<String>,
+  pub requested_scope: Vec<String>,
+  /// Hydra already has a remembered decision for this subject and client.
+  pub skip: bool,
+  pub subject: Option<String>,
+}
+
+/// The user's answer, forwarded by the backend to Hydra's accept or reject consent endpoint.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConsentDecision {
+  pub consent_challenge: String,
+  pub accept: bool,
+  pub grant_scope: Vec<String>,
+  pub grant_access_token_audience: Vec<String>,
+  pub remember: bool,
+}
+
+/// Hydra's `OAuth2RedirectTo`, where the browser continues the OAuth2 flow.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RedirectTo {
+  pub redirect_to: String,
+}
+
+/// Fetches the consent request from `GET {endpoint}?consent_challenge=`.
+pub async fn get_consent_request(
+  endpoint: &str,
+  consent_challenge: &str,
+) -> Result<ConsentRequest, FetchError> {
+  let challenge = String::from(web_sys::js_sys::encode_uri_component(consent_challenge));
+  get_json(&format!("{endpoint}?consent_challenge={challenge}")).await
+}
+
+/// Accepts or rejects the consent request with `POST {endpoint}`.
+pub async fn submit_consent(
+  endpoint: &str,
+  decision: &ConsentDecision,
+) -> Result<RedirectTo, FetchError> {
+  post_json(endpoint, decision).await
+}
@@ -0,0 +1,176 @@
+use crate::browser::local_storage;
+use dioxus::logger::tracing::error;
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::models::UiText;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Locales the UI is translated to, with their name and catalog.
+///
+/// Catalogs are JSON objects that map the app's English strings, and the ids of Kratos' `UiText`
+/// messages, to translations. Placeholders such as `{provider}` are filled in from the message
+/// context. Strings missing from a catalog are shown in English.
+const LOCALES: [(&str, &str, &str); 2] = [
+  ("en", "English", "{}"),
+  ("de", "Deutsch", include_str!("../locales/de.json")),
+];
+
+const DEFAULT_LOCALE: &str = "en";
+
+/// `localStorage` key the locale the user picked is kept under.
+const LOCALE_KEY: &str = "kratos-locale";
+
+static CATALOGS: LazyLock<HashMap<&'static str, HashMap<String, String>>> = LazyLock::new(|| {
+  LOCALES
+    .into_iter()
+    .map(|(locale, _, catalog)| {
+      let catalog = serde_json::from_str(catalog).unwrap_or_else(|err| {
+        error!("Invalid {locale} catalog: {err}");
+        HashMap::new()
+      });
+      (locale, catalog)
+    })
+    .collect()
+});
+
+/// The supported locale closest to `language`, e.g. `de` for `de-AT`.
+fn supported(language: &str) -> Option<&'static str> {
+  let language = language.to_lowercase();
+  let primary = language.split('-').next().unwrap_or_default();
+  LOCALES
+    .into_iter()
+    .map(|(locale, _, _)| locale)
+    .find(|locale| *locale == language || *locale == primary)
+}
+
+/// The locale the user picked before, or else the first of `navigator.languages` we support.
+fn initial_locale() -> &'static str {
+  if let Some(locale) = local_storage()
+    .and_then(|storage| storage.get_item(LOCALE_KEY).ok()?)
+    .and_then(|locale| supported(&locale))
+  {
+    return locale;
+  }
+
+  web_sys::window()
+    .map(|window| window.navigator().languages())
+    .and_then(|languages| {
+      languages
+        .iter()
+        .filter_map(|language| language.as_string())
+        .find_map(|language| supported(&language))
+    })
+    .unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Sets `<html lang>`, so the browser picks matching fonts, hyphenation and voices.
+fn set_document_lang(locale: &str) {
+  if let Some(html) = web_sys::window()
+    .and_then(|window| window.document())
+    .and_then(|document| document.document_element())
+    && let Err(err) = html.set_attribute("lang", locale)
+  {
+    error!("{err:?}");
+  }
+}
+
+/// Replaces `{name}` placeholders in `text` with the matching `args`.
+fn interpolate<'a>(text: &str, args: impl IntoIterator<Item = (&'a str, String)>) -> String {
+  args
+    .into_iter()
+    .fold(text.to_owned(), |text, (name, value)| {
+      text.replace(&format!("{{{name}}}"), &value)
+    })
+}
+
+/// The UI locale, provided as context by [`use_i18n_provider`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct I18n {
+  locale: Signal<&'static str>,
+}
+
+impl I18n {
+  pub fn locale(&self) -> &'static str {
+    *self.locale.read()
+  }
+
+  /// Switches to `locale` and remembers it for the next visit.
+  pub fn set_locale(&self, locale: &str) {
+    let Some(locale) = supported(locale) else {
+      error!("Unsupported locale {locale}");
+      return;
+    };
+
+    let mut current = self.locale;
+    current.set(locale);
+    set_document_lang(locale);
+    if let Some(storage) = local_storage()
+      && let Err(err) = storage.set_item(LOCALE_KEY, locale)
+    {
+      error!("{err:?}");
+    }
+  }
+
+  /// Supported locales and their names, e.g. `("de", "Deutsch")`.
+  pub fn locales(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+    LOCALES.into_iter().map(|(locale, name, _)| (locale, name))
+  }
+
+  fn lookup(&self, key: &str) -> Option<&'static String> {
+    CATALOGS.get(self.locale())?.get(key)
+  }
+
+  /// Translates one of the app's strings.
+  pub fn t(&self, text: &str) -> String {
+    self
+      .lookup(text)
+      .cloned()
+      .unwrap_or_else(|| text.to_owned())
+  }
+
+  /// Translates one of the app's strings and fills in its `{name}` placeholders.
+  pub fn t_with(&self, text: &str, args: &[(&str, &str)]) -> String {
+    interpolate(
+      &self.t(text),
+      args
+        .iter()
+        .map(|(name, value)| (*name, (*value).to_owned())),
+    )
+  }
+
+  /// Translates a Kratos message by its id, filling in placeholders from its context. Falls back
+  /// to the English text Kratos sent.
+  pub fn text(&self, text: &UiText) -> String {
+    let Some(translation) = self.lookup(&text.id.to_string()) else {
+      return text.text.to_owned();
+    };
+
+    let context = text.context.as_ref().and_then(Value::as_object);
+    interpolate(
+      translation,
+      context.into_iter().flatten().map(|(name, value)| {
+        let value = match value {
+          Value::String(value) => value.to_owned(),
+          value => value.to_string(),
+        };
+        (name.as_str(), value)
+      }),
+    )
+  }
+}
+
+pub fn use_i18n() -> I18n {
+  use_context()
+}
+
+/// Provides [`I18n`], starting with the user's preferred locale.
+pub fn use_i18n_provider() -> I18n {
+  use_context_provider(|| {
+    let locale = initial_locale();
+    set_document_lang(locale);
+    I18n {
+      locale: Signal::new(locale),
+    }
+  })
+}
@@ -0,0 +1,27 @@
+use crate::kratos::Kratos;
+use dioxus::logger::tracing::error;
+use ory_kratos_client_wasm::apis::identity_api::get_identity_schema;
+use serde_json::Value;
+
+/// Fetches the identity schema `id` from Kratos' public `/schemas/{id}`.
+pub async fn fetch(kratos: &Kratos, id: &str) -> Option<Value> {
+  match get_identity_schema(kratos, id).await {
+    Ok(schema) => Some(schema),
+    Err(err) => {
+      error!("Failed to load identity schema {id}: {err:?}");
+      None
+    }
+  }
+}
+
+/// The schema of an identity's `traits`.
+pub fn traits(schema: &Value) -> Option<&Value> {
+  schema.get("properties")?.get("traits")
+}
+
+/// The schema of the trait at the dotted `path`, e.g. `name.first`.
+pub fn property<'a>(schema: &'a Value, path: &str) -> Option<&'a Value> {
+  path.split('.').try_fold(traits(schema)?, |object, key| {
+    object.get("properties")?.get(key)
+  })
+}
@@ -0,0 +1,52 @@
+use crate::config::AppConfig;
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::configuration::Configuration;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// Kratos client configuration, built once in `App` and shared through context.
+///
+/// Dereferences to [`Configuration`] so it can be passed straight to the `frontend_api` calls.
+#[derive(Clone, Debug)]
+pub struct Kratos(Rc<Configuration>);
+
+impl Kratos {
+  pub fn new(config: &AppConfig) -> Self {
+    Kratos::from(Configuration {
+      base_path: config.kratos_url.to_owned(),
+      user_agent: config.user_agent.to_owned(),
+      basic_auth: None,
+      oauth_access_token: None,
+      bearer_access_token: None,
+      api_key: None,
+    })
+  }
+}
+
+impl From<Configuration> for Kratos {
+  fn from(configuration: Configuration) -> Self {
+    Kratos(Rc::new(configuration))
+  }
+}
+
+impl Deref for Kratos {
+  type Target = Configuration;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl PartialEq for Kratos {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+/// Returns the shared Kratos client configuration.
+///
+/// Provide a different [`Kratos`] with `use_context_provider` to point a subtree at another
+/// Kratos instance.
+pub fn use_kratos() -> Kratos {
+  use_context::<Kratos>()
+}
@@ -1,53 +1,61 @@
+mod branding;
+mod browser;
 mod components;
+mod config;
+mod continue_with;
+mod hydra;
+mod i18n;
+mod identity_schema;
+mod kratos;
+mod password;
+mod session;
+mod time;
 mod views;
+mod webauthn;
 
-use crate::components::{OryLogOut, SetSessionCookie, session_cookie_valid};
-use dioxus::logger::tracing::debug;
+use crate::branding::{use_branding, use_branding_provider};
+use crate::components::{
+  LocaleSwitcher, OryLogOut, RefreshSession, RouteGuard, SessionExpiry, ThemeSwitcher,
+};
+use crate::config::AppConfig;
+use crate::i18n::{use_i18n, use_i18n_provider};
+use crate::kratos::Kratos;
+use crate::session::{display_name, use_session, use_session_provider};
 use dioxus::prelude::*;
-use ory_kratos_client_wasm::apis::configuration::Configuration;
+use ory_kratos_client_wasm::models::AuthenticatorAssuranceLevel;
 
 use crate::views::{
-  AccountRecovery, LoginFlow, PageNotFound, RecoveryFlow, RegisterFlow, ServerError, SessionInfo,
-  Settings, SettingsFlow, SignIn, SignUp, VerificationFlow, Verify,
+  AccountRecovery, Consent, Home, LoginFlow, LoginParams, PageNotFound, RecoveryFlow, RegisterFlow,
+  SecondFactor, ServerError, SessionInfo, Sessions, Settings, SettingsFlow, SignIn, SignUp,
+  VerificationFlow, Verify,
 };
 
-const KRATOS_BROWSER_URL: &str = "http://127.0.0.1:4433";
-const SESSION_COOKIE_NAME: &str = "session_expiry";
-
-#[derive(Clone, Copy, Debug)]
-struct Session {
-  state: Signal<bool>,
-}
-
-trait Create {
-  fn create() -> Configuration;
-}
-
-impl Create for Configuration {
-  fn create() -> Configuration {
-    Configuration {
-      base_path: KRATOS_BROWSER_URL.to_owned(),
-      user_agent: None,
-      basic_auth: None,
-      oauth_access_token: None,
-      bearer_access_token: None,
-      api_key: None,
-    }
-  }
-}
-
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
 enum Route {
     #[layout(Navbar)]
+    #[layout(RouteGuard)]
       #[route("/")]
       Home {},
       #[route("/session")]
       SessionInfo {},
-      #[route("/sign-in")]
-      SignIn {},
+      #[route("/sessions")]
+      Sessions {},
+      #[route("/sign-in?:return_to&:refresh&:aal&:login_challenge&:organization&:via")]
+      SignIn {
+        return_to: String,
+        refresh: bool,
+        aal: String,
+        login_challenge: String,
+        organization: String,
+        via: String,
+      },
       #[route("/login?:flow")]
       LoginFlow { flow: String },
+      #[route("/second-factor?:return_to")]
+      SecondFactor { return_to: String },
+      #[route("/consent?:consent_challenge")]
+      Consent { consent_challenge: String },
       #[route("/sign-up")]
       SignUp {},
       #[route("/registration?:flow")]
@@ -64,8 +72,9 @@ enum Route {
       AccountRecovery {},
       #[route("/recovery?:flow")]
       RecoveryFlow { flow: String },
-      #[route("/session/local?:state")]
-      SetSessionCookie { state: bool },
+      #[route("/session/local")]
+      RefreshSession {},
+    #[end_layout]
     #[end_layout]
     // PageNotFound is a catch all route that will match any route and placing the matched segments in the route field
     #[route("/error?:id")]
@@ -74,6 +83,51 @@ enum Route {
     PageNotFound { route: Vec<String> },
 }
 
+/// Session a route requires, enforced by [`RouteGuard`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Access {
+  Any,
+  /// Signed in with at least the given assurance level.
+  Session(AuthenticatorAssuranceLevel),
+  /// Signed out, signed in users are sent home.
+  Anonymous,
+}
+
+impl Route {
+  /// `SignIn` without any login flow parameters.
+  fn sign_in() -> Self {
+    LoginParams::default().into()
+  }
+
+  /// Where `/sign-in` continues once signed in, if it was given a `return_to`.
+  fn return_to(&self) -> Option<&str> {
+    match self {
+      Route::SignIn { return_to, .. } if !return_to.is_empty() => Some(return_to),
+      _ => None,
+    }
+  }
+
+  fn access(&self) -> Access {
+    match self {
+      Route::SessionInfo {}
+      | Route::Sessions {}
+      | Route::Settings {}
+      | Route::SettingsFlow { .. } => Access::Session(AuthenticatorAssuranceLevel::Aal1),
+      // Re-authentication, step-up and Hydra logins start from an existing session.
+      Route::SignIn {
+        refresh,
+        aal,
+        login_challenge,
+        ..
+      } if !refresh && aal.is_empty() && login_challenge.is_empty() => Access::Anonymous,
+      Route::SignUp {} | Route::RegisterFlow { .. } | Route::AccountRecovery {} => {
+        Access::Anonymous
+      }
+      _ => Access::Any,
+    }
+  }
+}
+
 fn main() {
   dioxus::logger::initialize_default();
   dioxus::launch(App);
@@ -81,12 +135,7 @@ fn main() {
 
 #[component]
 fn App() -> Element {
-  use_context_provider(|| Session {
-    state: Signal::new(false),
-  });
-
-  let set_state = use_resource(move || async move { session_cookie_valid().await });
-  (set_state)();
+  let config = use_resource(AppConfig::load);
 
   rsx! {
     document::Link {
@@ -97,70 +146,77 @@ fn App() -> Element {
       rel: "stylesheet",
       href: asset!("/assets/tailwind.css", AssetOptions::builder().with_hash_suffix(false)),
     }
-    Router::<Route> {}
+    if let Some(config) = config() {
+      Provider { config }
+    }
   }
 }
 
-/// Home page
+/// Provides the runtime configuration, Kratos client, branding, locale and session state, then
+/// renders the router.
 #[component]
-fn Home() -> Element {
+fn Provider(config: AppConfig) -> Element {
+  use_context_provider(|| Kratos::new(&config));
+  let branding = use_branding_provider(config.branding.to_owned());
+  use_context_provider(|| config);
+  use_i18n_provider();
+  use_session_provider();
+
   rsx! {
-    article { class: "max-w-none",
-      h1 { class: "mb-8 text-4xl font-extrabold", "Welcome to the Ory Account Experience!" }
-      p { class: "my-5 text-base-content opacity-80",
-        "Let your customers sign up, log in and manage their account using Ory's standard experience. Here you can preview, test and learn to integrate it into your application."
-      }
-      hr { class: "my-12 border-base-content" }
-      h2 { class: "my-4 text-2xl font-bold", "Core concepts" }
-      p { "Here are some useful documentation pieces that help you get started right away." }
-    }
-    Cards {}
+    document::Title { {branding.product_name} }
+    Router::<Route> {}
   }
 }
 
 /// Shared navbar component.
 #[component]
 fn Navbar() -> Element {
-  debug!("{:?}", use_context::<Session>());
+  let session = use_session();
+  let i18n = use_i18n();
+  let branding = use_branding();
+  let welcome = i18n.t_with(
+    "Welcome to {product}",
+    &[("product", &branding.product_name)],
+  );
 
-  let links = if *use_context::<Session>().state.read() {
+  let links = if session.is_authenticated() {
     rsx! {
       li { class: "menu-disabled",
-        Link { to: Route::SignIn {}, "Sign In" }
+        Link { to: Route::sign_in(), {i18n.t("Sign In")} }
       }
       li { class: "menu-disabled",
-        Link { to: Route::SignUp {}, "Sign Up" }
+        Link { to: Route::SignUp {}, {i18n.t("Sign Up")} }
       }
       li { class: "menu-disabled",
-        Link { to: Route::AccountRecovery {}, "Account Recovery" }
+        Link { to: Route::AccountRecovery {}, {i18n.t("Account Recovery")} }
       }
       li {
-        Link { to: Route::Verify {}, "Account Verification" }
+        Link { to: Route::Verify {}, {i18n.t("Account Verification")} }
       }
       li {
-        Link { to: Route::Settings {}, "Account Settings" }
+        Link { to: Route::Settings {}, {i18n.t("Account Settings")} }
       }
       OryLogOut {}
     }
   } else {
     rsx! {
       li {
-        Link { to: Route::SignIn {}, "Sign In" }
+        Link { to: Route::sign_in(), {i18n.t("Sign In")} }
       }
       li {
-        Link { to: Route::SignUp {}, "Sign Up" }
+        Link { to: Route::SignUp {}, {i18n.t("Sign Up")} }
       }
       li {
-        Link { to: Route::AccountRecovery {}, "Account Recovery" }
+        Link { to: Route::AccountRecovery {}, {i18n.t("Account Recovery")} }
       }
       li {
-        Link { to: Route::Verify {}, "Account Verification" }
+        Link { to: Route::Verify {}, {i18n.t("Account Verification")} }
       }
       li { class: "menu-disabled",
-        Link { to: Route::Settings {}, "Account Settings" }
+        Link { to: Route::Settings {}, {i18n.t("Account Settings")} }
       }
       li { class: "menu-disabled",
-        a { href: "", "Log out" }
+        a { href: "", {i18n.t("Log out")} }
       }
     }
   };
@@ -173,87 +229,65 @@ fn Navbar() -> Element {
         r#type: "checkbox",
       }
       div { class: "drawer-content flex flex-col max-w-none p-4 lg:p-12",
-        span { class: "py-4",
+        span { class: "py-4 print:hidden",
           label {
             class: "btn btn-primary drawer-button lg:hidden py-2",
             r#for: "nav-drawer",
-            "Menu"
+            {i18n.t("Menu")}
           }
         }
         Outlet::<Route> {}
+        SessionExpiry {}
+        if !branding.footer_links.is_empty() {
+          footer { class: "footer footer-horizontal footer-center mt-auto pt-12 print:hidden",
+            nav { class: "grid grid-flow-col gap-4",
+              for link in branding.footer_links {
+                a { key: "{link.href}", class: "link link-hover", href: link.href, {link.label} }
+              }
+            }
+          }
+        }
       }
-      div { class: "drawer-side",
+      div { class: "drawer-side print:hidden",
         label {
           aria_label: "close sidebar",
           class: "drawer-overlay",
           r#for: "nav-drawer",
         }
         ul { class: "menu menu-lg bg-base-200 text-base-content min-h-full w-80 p-4",
-          li { class: "menu-title text-primary", "Welcome to Ory" }
-          li {
-            Link { to: Route::Home {}, "Home" }
+          li { class: "menu-title text-primary",
+            if let Some(logo_url) = branding.logo_url {
+              img {
+                class: "h-10 w-fit mb-2",
+                src: logo_url,
+                alt: branding.product_name.to_owned(),
+              }
+            }
+            {welcome}
           }
-          li {
-            Link { to: Route::SessionInfo {}, "Session Information" }
+          if let Some(identity) = session.identity() {
+            li { class: "menu-title",
+              {i18n.t("Signed in as ")}
+              {display_name(&identity)}
+            }
           }
           li {
-            h2 { class: "menu-title", "Default User Interfaces" }
-            ul { {links} }
+            Link { to: Route::Home {}, {i18n.t("Home")} }
           }
-        }
-      }
-    }
-  }
-}
-
-#[component]
-pub fn Cards() -> Element {
-  rsx! {
-    div { class: "xl:flex xl:flex-row xl:justify-between",
-      div { class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
-        a { href: "https://www.ory.sh/docs/getting-started/integrate-auth/expressjs",
-          div { class: "card-body",
-            h2 { class: "card-title", "Getting Started" }
-            p {
-              "Jump start your project and complete the quickstart tutorial to get a broader overview of Ory Network."
-            }
-          }
-        }
-      }
-      div { class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
-        a { href: "https://www.ory.sh/docs/kratos/self-service",
-          div { class: "card-body",
-            h2 { class: "card-title", "User flows" }
-            p {
-              "Implement flows that users perform themselves as opposed to administrative intervention."
-            }
+          li {
+            Link { to: Route::SessionInfo {}, {i18n.t("Session Information")} }
           }
-        }
-      }
-      div { class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
-        a { href: "https://www.ory.sh/docs/kratos/manage-identities/identity-schema",
-          div { class: "card-body",
-            h2 { class: "card-title", "Identities 101" }
-            p {
-              "Every identity can have its own model - get to know the ins and outs of Identity Schemas."
-            }
+          li {
+            Link { to: Route::Sessions {}, {i18n.t("Devices & Sessions")} }
           }
-        }
-      }
-      div { class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
-        a { href: "https://www.ory.sh/docs/kratos/session-management/overview",
-          div { class: "card-body",
-            h2 { class: "card-title", "Sessions" }
-            p { "Ory Network manages sessions for you - get to know how sessions work." }
+          li {
+            h2 { class: "menu-title", {i18n.t("Default User Interfaces")} }
+            ul { {links} }
           }
-        }
-      }
-      div { class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
-        a { href: "https://www.ory.sh/docs/kratos/bring-your-own-ui/configure-ory-to-use-your-ui",
-          div { class: "card-body",
-            h2 { class: "card-title", "Custom UI" }
-            p {
-              "Implementing these pages in your language and framework of choice is straightforward using our SDKs."
+          li { class: "mt-auto",
+            div { class: "flex flex-col items-stretch gap-2",
+              ThemeSwitcher {}
+              LocaleSwitcher {}
             }
           }
         }
@@ -0,0 +1,141 @@
+/// Common passwords and words, which attackers try first.
+const COMMON: [&str; 32] = [
+  "password",
+  "passw0rd",
+  "123456",
+  "12345678",
+  "123456789",
+  "qwerty",
+  "qwertz",
+  "azerty",
+  "letmein",
+  "welcome",
+  "admin",
+  "iloveyou",
+  "monkey",
+  "dragon",
+  "football",
+  "baseball",
+  "master",
+  "sunshine",
+  "princess",
+  "abc123",
+  "login",
+  "starwars",
+  "trustno1",
+  "hello",
+  "freedom",
+  "whatever",
+  "shadow",
+  "superman",
+  "secret",
+  "summer",
+  "winter",
+  "changeme",
+];
+
+/// `log10` of the guesses needed for each score above 0, as in zxcvbn.
+const THRESHOLDS: [f64; 4] = [3.0, 6.0, 8.0, 10.0];
+
+/// How many different characters the password could be drawn from, judging by the kinds it has.
+fn charset_size(password: &str) -> f64 {
+  let kinds = [
+    (password.chars().any(|c| c.is_ascii_lowercase()), 26.0),
+    (password.chars().any(|c| c.is_ascii_uppercase()), 26.0),
+    (password.chars().any(|c| c.is_ascii_digit()), 10.0),
+    (
+      password
+        .chars()
+        .any(|c| c.is_ascii_punctuation() || c == ' '),
+      33.0,
+    ),
+    (!password.is_ascii(), 100.0),
+  ];
+  kinds
+    .into_iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum()
+}
+
+/// Whether `c` repeats `previous` or continues a sequence such as `abc` or `321`.
+fn continues(previous: char, c: char) -> bool {
+  let (previous, c) = (previous.to_ascii_lowercase(), c.to_ascii_lowercase());
+  c.is_ascii_alphanumeric() && (c as i32 - previous as i32).abs() <= 1
+}
+
+/// Longest run of characters `a` and `b` have in common.
+pub fn longest_common_substring(a: &str, b: &str) -> usize {
+  let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+  let mut longest = 0;
+  let mut previous = vec![0; b.len() + 1];
+  for x in &a {
+    let mut current = vec![0; b.len() + 1];
+    for (j, y) in b.iter().enumerate() {
+      if x == y {
+        current[j + 1] = previous[j] + 1;
+        longest = longest.max(current[j + 1]);
+      }
+    }
+    previous = current;
+  }
+  longest
+}
+
+/// Whether `password` is mostly made of `identifier`, such as the local part of an email address.
+pub fn similar(password: &str, identifier: &str) -> bool {
+  let identifier = identifier
+    .split('@')
+    .next()
+    .unwrap_or_default()
+    .to_lowercase();
+  let password = password.to_lowercase();
+  identifier.chars().count() >= 3
+    && longest_common_substring(&password, &identifier) * 2 >= password.chars().count()
+}
+
+/// Scores `password` from 0, too guessable, to 4, very unguessable, in the spirit of zxcvbn: by
+/// roughly how many guesses an attacker needs, with common passwords, repeats and sequences
+/// counting for little. `user_inputs` are values the user entered elsewhere, such as their email
+/// address, which make poor passwords too.
+pub fn score(password: &str, user_inputs: &[&str]) -> u8 {
+  if password.is_empty() {
+    return 0;
+  }
+
+  let lower = password.to_lowercase();
+  let mut free = vec![false; lower.len()];
+
+  // Known words and the user's own inputs are guessed as a whole.
+  let mut words = 0;
+  let inputs = user_inputs
+    .iter()
+    .flat_map(|input| input.split(['@', '.', ' ']))
+    .filter(|part| part.chars().count() >= 3)
+    .map(str::to_lowercase);
+  for word in COMMON.iter().map(|word| word.to_string()).chain(inputs) {
+    for (start, _) in lower.match_indices(&word) {
+      free[start..start + word.len()].fill(true);
+      words += 1;
+    }
+  }
+
+  let mut length = 0.0;
+  let mut previous = None;
+  for (index, c) in lower.char_indices() {
+    if !free[index] {
+      length += match previous {
+        Some(previous) if continues(previous, c) => 0.25,
+        _ => 1.0,
+      };
+    }
+    previous = Some(c);
+  }
+
+  let guesses =
+    length * charset_size(password).log10() + words as f64 * (COMMON.len() as f64).log10();
+  THRESHOLDS
+    .iter()
+    .take_while(|threshold| guesses >= **threshold)
+    .count() as u8
+}
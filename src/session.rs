@@ -0,0 +1,246 @@
+use crate::kratos::{Kratos, use_kratos};
+use crate::time::parse;
+use chrono::{DateTime, Utc};
+use dioxus::logger::tracing::{debug, error};
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::frontend_api::{ToSessionError, to_session};
+use ory_kratos_client_wasm::apis::{Error, ResponseContent};
+use ory_kratos_client_wasm::models::{AuthenticatorAssuranceLevel, Identity, Session};
+use web_sys::BroadcastChannel;
+use web_sys::wasm_bindgen::JsValue;
+
+/// How often the session is checked while the page is open.
+const REFRESH_INTERVAL_MS: u32 = 60_000;
+
+/// Asks for a refresh whenever the page regains focus or becomes visible, and on a timer.
+const REFRESH_EVENTS_JS: &str = r#"
+const refresh = () => dioxus.send(true);
+window.addEventListener("focus", refresh);
+document.addEventListener("visibilitychange", () => {
+  if (document.visibilityState === "visible") refresh();
+});
+setInterval(refresh, REFRESH_INTERVAL_MS);
+"#;
+
+/// `BroadcastChannel` tabs announce session changes on, and the `localStorage` key used instead
+/// where it is unavailable.
+const SYNC_CHANNEL: &str = "kratos-session";
+
+/// Forwards session changes announced by other tabs.
+const SYNC_EVENTS_JS: &str = r#"
+if ("BroadcastChannel" in window) {
+  new BroadcastChannel("SYNC_CHANNEL").onmessage = (event) => dioxus.send(event.data);
+}
+window.addEventListener("storage", (event) => {
+  if (event.key === "SYNC_CHANNEL" && event.newValue) dioxus.send(event.newValue.split(":")[0]);
+});
+"#;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum SessionState {
+  /// Not checked yet.
+  #[default]
+  Loading,
+  SignedOut,
+  /// Signed in, but Kratos requires a second factor before handing out the session.
+  SecondFactorRequired,
+  SignedIn(Box<Session>),
+}
+
+impl SessionState {
+  /// Name of the change to this state announced to other tabs.
+  fn event(&self) -> &'static str {
+    match self {
+      SessionState::Loading => "loading",
+      SessionState::SignedOut => "signed_out",
+      SessionState::SecondFactorRequired => "second_factor_required",
+      SessionState::SignedIn(_) => "signed_in",
+    }
+  }
+}
+
+/// Tells other tabs that the session changed, so they check it too.
+fn broadcast(state: &SessionState) {
+  let event = state.event();
+  match BroadcastChannel::new(SYNC_CHANNEL) {
+    Ok(channel) => {
+      if let Err(err) = channel.post_message(&JsValue::from_str(event)) {
+        error!("Failed to broadcast {event}: {err:?}");
+      }
+      channel.close();
+    }
+    // The timestamp makes every write a change, which is what fires `storage` events.
+    Err(_) => {
+      if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok()?)
+        && let Err(err) = storage.set_item(
+          SYNC_CHANNEL,
+          &format!("{event}:{}", Utc::now().timestamp_millis()),
+        )
+      {
+        error!("Failed to broadcast {event}: {err:?}");
+      }
+    }
+  }
+}
+
+/// The Kratos session, as last reported by `/sessions/whoami`. Provided as context by
+/// [`use_session_provider`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionStore {
+  kratos: Kratos,
+  state: Signal<SessionState>,
+}
+
+impl SessionStore {
+  pub fn state(&self) -> SessionState {
+    self.state.read().to_owned()
+  }
+
+  pub fn session(&self) -> Option<Session> {
+    match &*self.state.read() {
+      SessionState::SignedIn(session) => Some(*session.to_owned()),
+      _ => None,
+    }
+  }
+
+  pub fn is_loading(&self) -> bool {
+    *self.state.read() == SessionState::Loading
+  }
+
+  /// Whether there is an active session that has not expired yet.
+  pub fn is_authenticated(&self) -> bool {
+    self.session().is_some_and(|session| {
+      session.active != Some(false) && expires_at(&session).is_none_or(|at| at > Utc::now())
+    })
+  }
+
+  pub fn aal(&self) -> Option<AuthenticatorAssuranceLevel> {
+    self
+      .session()
+      .and_then(|session| session.authenticator_assurance_level)
+  }
+
+  pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+    self.session().as_ref().and_then(expires_at)
+  }
+
+  pub fn identity(&self) -> Option<Identity> {
+    self
+      .session()
+      .and_then(|session| session.identity)
+      .map(|identity| *identity)
+  }
+
+  /// Forgets the session without asking Kratos, e.g. once it has expired.
+  pub fn expire(&self) {
+    let mut state = self.state;
+    state.set(SessionState::SignedOut);
+  }
+
+  /// Fetches the session from Kratos, and tells other tabs if the user signed in or out. The first
+  /// check after a page load isn't announced, other tabs already know the session.
+  pub async fn refresh(&self) {
+    if let Some((previous, state)) = self.fetch().await
+      && previous != SessionState::Loading
+      && previous.event() != state.event()
+    {
+      broadcast(&state);
+    }
+  }
+
+  /// Fetches the session after another tab announced a change, without announcing it again.
+  async fn sync(&self) {
+    self.fetch().await;
+  }
+
+  /// Fetches the session from Kratos and returns the previous and new state if it changed. Other
+  /// errors than `401` and `403` keep the current state, so a flaky connection doesn't sign the
+  /// user out.
+  async fn fetch(&self) -> Option<(SessionState, SessionState)> {
+    let state = match to_session(&self.kratos, None, None, None).await {
+      Ok(session) => SessionState::SignedIn(Box::new(session)),
+      Err(Error::ResponseError(ResponseContent {
+        entity: Some(ToSessionError::Status401(_)),
+        ..
+      })) => SessionState::SignedOut,
+      Err(Error::ResponseError(ResponseContent {
+        entity: Some(ToSessionError::Status403(_)),
+        ..
+      })) => SessionState::SecondFactorRequired,
+      Err(err) => {
+        error!("Failed to refresh session: {err:?}");
+        if !self.is_loading() {
+          return None;
+        }
+        SessionState::SignedOut
+      }
+    };
+
+    debug!("{state:?}");
+    let mut current = self.state;
+    if *current.peek() == state {
+      return None;
+    }
+    let previous = current.replace(state.to_owned());
+    Some((previous, state))
+  }
+}
+
+fn expires_at(session: &Session) -> Option<DateTime<Utc>> {
+  session.expires_at.as_deref().and_then(parse)
+}
+
+/// A short name for `identity`: its email or username trait, or else its id.
+pub fn display_name(identity: &Identity) -> String {
+  identity
+    .traits
+    .as_ref()
+    .and_then(|traits| {
+      ["email", "username"]
+        .into_iter()
+        .find_map(|trait_name| traits.get(trait_name)?.as_str())
+    })
+    .unwrap_or(&identity.id)
+    .to_owned()
+}
+
+pub fn use_session() -> SessionStore {
+  use_context()
+}
+
+/// Provides the [`SessionStore`] and keeps it in sync with Kratos and other tabs.
+pub fn use_session_provider() -> SessionStore {
+  let kratos = use_kratos();
+  let store = use_context_provider(|| SessionStore {
+    kratos,
+    state: Signal::new(SessionState::Loading),
+  });
+
+  let refresh_store = store.to_owned();
+  use_future(move || {
+    let store = refresh_store.to_owned();
+    async move {
+      store.refresh().await;
+
+      let js = REFRESH_EVENTS_JS.replace("REFRESH_INTERVAL_MS", &REFRESH_INTERVAL_MS.to_string());
+      let mut events = document::eval(&js);
+      while events.recv::<bool>().await.is_ok() {
+        store.refresh().await;
+      }
+    }
+  });
+
+  let sync_store = store.to_owned();
+  use_future(move || {
+    let store = sync_store.to_owned();
+    async move {
+      let mut events = document::eval(&SYNC_EVENTS_JS.replace("SYNC_CHANNEL", SYNC_CHANNEL));
+      while let Ok(event) = events.recv::<String>().await {
+        debug!("Session {event} in another tab");
+        store.sync().await;
+      }
+    }
+  });
+
+  store
+}
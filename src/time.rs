@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use dioxus::logger::tracing::error;
+use web_sys::js_sys::Intl::RelativeTimeFormat;
+use web_sys::js_sys::{Array, Date, Object, Reflect};
+use web_sys::wasm_bindgen::JsValue;
+
+/// Units relative times are given in, with their length in seconds, largest first.
+const UNITS: [(&str, i64); 6] = [
+  ("year", 365 * 24 * 60 * 60),
+  ("month", 30 * 24 * 60 * 60),
+  ("week", 7 * 24 * 60 * 60),
+  ("day", 24 * 60 * 60),
+  ("hour", 60 * 60),
+  ("minute", 60),
+];
+
+/// Parses an RFC 3339 timestamp as Kratos sends them.
+pub fn parse(rfc3339: &str) -> Option<DateTime<Utc>> {
+  match DateTime::parse_from_rfc3339(rfc3339) {
+    Ok(at) => Some(at.with_timezone(&Utc)),
+    Err(err) => {
+      error!("Invalid timestamp {rfc3339}: {err:?}");
+      None
+    }
+  }
+}
+
+/// `at` in `locale` and the browser's time zone, e.g. `10/18/2026, 2:05:00 PM` in `en`.
+pub fn local(at: DateTime<Utc>, locale: &str) -> String {
+  let date = Date::new(&JsValue::from_f64(at.timestamp_millis() as f64));
+  date.to_locale_string(locale, &JsValue::UNDEFINED).into()
+}
+
+/// How far `at` is from now in `locale`, e.g. `in 23 hours` or `5 minutes ago` in `en`.
+pub fn relative(at: DateTime<Utc>, locale: &str) -> String {
+  let seconds = (at - Utc::now()).num_seconds();
+  let (unit, length) = UNITS
+    .into_iter()
+    .find(|(_, length)| seconds.abs() >= *length)
+    .unwrap_or(("second", 1));
+
+  // `auto` says "now" and "tomorrow" rather than "in 0 seconds" and "in 1 day".
+  let options = Object::new();
+  if let Err(err) = Reflect::set(&options, &"numeric".into(), &"auto".into()) {
+    error!("{err:?}");
+  }
+  RelativeTimeFormat::new(&Array::of1(&JsValue::from_str(locale)), &options)
+    .format((seconds / length) as f64, unit)
+    .into()
+}
@@ -0,0 +1,186 @@
+use crate::Route;
+use crate::branding::use_branding;
+use crate::browser::redirect;
+use crate::config::AppConfig;
+use crate::hydra::{ConsentDecision, get_consent_request, submit_consent};
+use crate::i18n::use_i18n;
+use dioxus::logger::tracing::{debug, error};
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+/// Ory Hydra consent screen, see the `consent_endpoint` configuration.
+#[component]
+pub fn Consent(consent_challenge: String) -> Element {
+  let endpoint = use_context::<AppConfig>()
+    .consent_endpoint
+    .unwrap_or_default();
+  let configured = !endpoint.is_empty();
+  let i18n = use_i18n();
+  let branding = use_branding();
+
+  let mut denied = use_signal(HashSet::<String>::new);
+  let mut remember = use_signal(|| false);
+  let mut submitting = use_signal(|| false);
+  let mut failure = use_signal(|| None::<String>);
+
+  let request_endpoint = endpoint.clone();
+  // Without an endpoint there is nothing to ask, the request would go to the page itself.
+  let get_request = use_resource(move || {
+    let endpoint = request_endpoint.to_owned();
+    let challenge = consent_challenge.to_owned();
+    async move {
+      if endpoint.is_empty() {
+        return None;
+      }
+      Some(get_consent_request(&endpoint, &challenge).await)
+    }
+  });
+
+  let decide = use_callback(move |accept: bool| {
+    let Some(Some(Ok(request))) = &*get_request.read() else {
+      return;
+    };
+
+    let decision = ConsentDecision {
+      consent_challenge: request.challenge.to_owned(),
+      accept,
+      grant_scope: request
+        .requested_scope
+        .iter()
+        .filter(|scope| !denied.read().contains(*scope))
+        .cloned()
+        .collect(),
+      grant_access_token_audience: request.requested_access_token_audience.to_owned(),
+      remember: remember(),
+    };
+
+    let endpoint = endpoint.to_owned();
+    submitting.set(true);
+    spawn(async move {
+      match submit_consent(&endpoint, &decision).await {
+        Ok(res) => redirect(&res.redirect_to),
+        Err(err) => {
+          error!("{err}");
+          failure.set(Some(err.to_string()));
+          submitting.set(false);
+        }
+      }
+    });
+  });
+
+  // Hydra remembered an earlier decision, there is nothing to ask.
+  use_effect(move || {
+    if let Some(Some(Ok(request))) = &*get_request.read()
+      && request.skip
+    {
+      decide.call(true);
+    }
+  });
+
+  if !configured {
+    return rsx! {
+      div { class: "text-center max-h-screen max-w-none",
+        h1 { class: "text-2xl my-8", {i18n.t("OAuth2 consent is not configured.")} }
+        Link { to: Route::Home {}, class: "btn btn-primary my-8", {i18n.t("Go Home")} }
+      }
+    };
+  }
+
+  return match &*get_request.read() {
+    Some(Some(Ok(request))) => {
+      debug!("{request:#?}");
+      let client = request.client.to_owned().unwrap_or_default();
+      let name = client
+        .client_name
+        .filter(|name| !name.is_empty())
+        .or(client.client_id)
+        .unwrap_or_else(|| i18n.t("An application"));
+      let client_name = [("name", name.as_str())];
+
+      rsx! {
+        h1 { class: "text-center text-2xl", {i18n.t_with("Authorize {name}", &client_name)} }
+        div { class: "mx-auto w-full max-w-lg",
+          div { class: "mt-10",
+            if let Some(logo) = client.logo_uri {
+              img {
+                class: "mx-auto h-16 mb-4",
+                src: logo,
+                alt: name.clone(),
+              }
+            }
+            if let Some(failure) = failure() {
+              div { role: "alert", class: "alert alert-error",
+                span { {failure} }
+              }
+            }
+            fieldset { class: "fieldset",
+              legend { class: "fieldset-legend text-xl",
+                {i18n.t_with("{name} is requesting access to:", &client_name)}
+              }
+              for scope in request.requested_scope.iter().cloned() {
+                label { class: "label my-1",
+                  input {
+                    class: "checkbox",
+                    r#type: "checkbox",
+                    checked: !denied.read().contains(&scope),
+                    onchange: {
+                        let scope = scope.to_owned();
+                        move |evt: FormEvent| {
+                            if evt.checked() {
+                                denied.write().remove(&scope);
+                            } else {
+                                denied.write().insert(scope.to_owned());
+                            }
+                        }
+                    },
+                  }
+                  {scope.to_owned()}
+                }
+              }
+              label { class: "label my-4",
+                input {
+                  class: "checkbox",
+                  r#type: "checkbox",
+                  checked: remember(),
+                  onchange: move |evt: FormEvent| remember.set(evt.checked()),
+                }
+                {i18n.t("Remember this decision")}
+              }
+              button {
+                class: "{branding.button_class} w-full my-2",
+                disabled: submitting(),
+                onclick: move |_| decide.call(true),
+                {i18n.t("Allow")}
+              }
+              button {
+                class: "btn btn-outline w-full my-2",
+                disabled: submitting(),
+                onclick: move |_| decide.call(false),
+                {i18n.t("Deny")}
+              }
+            }
+            p { class: "text-sm leading-6",
+              if let Some(policy_uri) = client.policy_uri {
+                a { class: "link-primary link-hover mr-4", href: policy_uri, {i18n.t("Privacy Policy")} }
+              }
+              if let Some(tos_uri) = client.tos_uri {
+                a { class: "link-primary link-hover", href: tos_uri, {i18n.t("Terms of Service")} }
+              }
+            }
+          }
+        }
+      }
+    }
+    Some(Some(Err(err))) => {
+      error!("{err}");
+      rsx! {
+        div { class: "text-center max-h-screen max-w-none",
+          h1 { class: "text-2xl my-8", {i18n.t("Failed to get the consent request.")} }
+          p { class: "font-light m-8", "{err}" }
+          Link { to: Route::Home {}, class: "btn btn-primary my-8", {i18n.t("Go Home")} }
+        }
+      }
+    }
+    _ => rsx! {},
+  };
+}
@@ -1,33 +1,45 @@
-use crate::{Configuration, Create, Route};
+use crate::Route;
+use crate::i18n::use_i18n;
+use crate::kratos::use_kratos;
 use dioxus::prelude::*;
 use ory_kratos_client_wasm::apis::frontend_api::get_flow_error;
 
 #[component]
 pub fn PageNotFound(route: Vec<String>) -> Element {
+  let i18n = use_i18n();
+
   rsx! {
     div { class: "text-center max-h-screen max-w-none",
       h1 { class: "text-9xl my-12", "404" }
-      h2 { class: "text-2xl my-8", "Oops! Page not found." }
+      h2 { class: "text-2xl my-8", {i18n.t("Oops! Page not found.")} }
       h3 { class: "font-light my-8",
-        "The page {route:?} might have been removed or is temporarily unavailable."
+        {
+            i18n.t_with(
+                "The page {route} might have been removed or is temporarily unavailable.",
+                &[("route", &format!("{route:?}"))],
+            )
+        }
       }
-      Link { to: Route::Home {}, class: "btn btn-primary my-8", "Go Home" }
+      Link { to: Route::Home {}, class: "btn btn-primary my-8", {i18n.t("Go Home")} }
     }
   }
 }
 
 #[component]
 pub fn ServerError(id: String) -> Element {
+  let i18n = use_i18n();
   let err_id = id.clone();
+  let kratos = use_kratos();
   let future = use_resource(move || {
     let err_id = err_id.to_owned();
-    async move { get_flow_error(&Configuration::create(), &err_id).await }
+    let kratos = kratos.to_owned();
+    async move { get_flow_error(&kratos, &err_id).await }
   });
 
   rsx! {
     div { class: "text-center max-h-screen max-w-none",
-      h1 { class: "text-2xl my-8", "Oops! We've encountered an error." }
-      h2 { class: "font-light my-8", "Error ID: {id}" }
+      h1 { class: "text-2xl my-8", {i18n.t("Oops! We've encountered an error.")} }
+      h2 { class: "font-light my-8", {i18n.t_with("Error ID: {id}", &[("id", &id)])} }
 
       match &*future.read_unchecked() {
           Some(Ok(res)) => {
@@ -41,7 +53,7 @@ pub fn ServerError(id: String) -> Element {
                           }
                       } else {
                           rsx! {
-                            p { class: "font-light m-8", "Error: {Value:?}" }
+                            p { class: "font-light m-8", {i18n.t("Error:")} " {Value:?}" }
                           }
                       }
                   }
@@ -49,13 +61,13 @@ pub fn ServerError(id: String) -> Element {
               }
           }
           Some(Err(err)) => rsx! {
-            p { class: "font-light m-8", "Failed to get error: {err}" }
+            p { class: "font-light m-8", {i18n.t("Failed to get error:")} " {err}" }
           },
           None => rsx! {
-            p { class: "font-light my-8", "Fetching error..." }
+            p { class: "font-light my-8", {i18n.t("Fetching error...")} }
           },
       }
-      Link { to: Route::Home {}, class: "btn btn-primary my-8", "Go Home" }
+      Link { to: Route::Home {}, class: "btn btn-primary my-8", {i18n.t("Go Home")} }
     }
   }
 }
@@ -0,0 +1,67 @@
+use crate::Route;
+use crate::branding::use_branding;
+use crate::browser::redirect;
+use crate::config::AppConfig;
+use crate::session::{SessionState, use_session};
+use crate::views::LoginParams;
+use dioxus::prelude::*;
+
+/// Home page, with the configured content, or a redirect to the configured application.
+#[component]
+pub fn Home() -> Element {
+  let home = use_context::<AppConfig>().home;
+  let branding = use_branding();
+  let session = use_session();
+
+  if let Some(app_url) = home.app_url {
+    match session.state() {
+      SessionState::Loading => {}
+      _ if session.is_authenticated() => redirect(&app_url),
+      SessionState::SecondFactorRequired => {
+        navigator().replace(Route::SecondFactor { return_to: app_url });
+      }
+      _ => {
+        navigator().replace(Route::from(LoginParams {
+          return_to: app_url,
+          ..Default::default()
+        }));
+      }
+    }
+    return rsx! {};
+  }
+
+  let title = home.title.replace("{product}", &branding.product_name);
+
+  rsx! {
+    article { class: "max-w-none",
+      h1 { class: "mb-8 text-4xl font-extrabold", {title} }
+      for (index, section) in home.sections.into_iter().enumerate() {
+        if let Some(heading) = section.heading {
+          if index > 0 {
+            hr { class: "my-12 border-base-content" }
+          }
+          h2 { class: "my-4 text-2xl font-bold", {heading} }
+        }
+        for paragraph in section.body.split("\n\n") {
+          p { class: "my-5 text-base-content opacity-80", {paragraph.trim().to_owned()} }
+        }
+      }
+    }
+    if !home.cards.is_empty() {
+      div { class: "xl:flex xl:flex-row xl:justify-between",
+        for card in home.cards {
+          div {
+            key: "{card.href}",
+            class: "card bg-primary hover:bg-primary/90 text-primary-content xl:h-full my-4 xl:basis-1/6",
+            a { href: card.href,
+              div { class: "card-body",
+                h2 { class: "card-title", {card.title} }
+                p { {card.description} }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
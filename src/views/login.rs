@@ -1,101 +1,213 @@
-use crate::components::{DisplayError, FormBuilder};
-use crate::{Configuration, Create, Route};
-use dioxus::logger::tracing::{debug, error};
+use crate::Route;
+use crate::components::{FlowPage, SelfServiceFlow, Submitted, submitted};
+use crate::i18n::I18n;
+use crate::kratos::Kratos;
 use dioxus::prelude::*;
-use ory_kratos_client_wasm::apis::frontend_api::{create_browser_login_flow, get_login_flow};
+use ory_kratos_client_wasm::apis::Error;
+use ory_kratos_client_wasm::apis::frontend_api::{
+  CreateBrowserLoginFlowError, GetLoginFlowError, UpdateLoginFlowError, create_browser_login_flow,
+  get_login_flow, update_login_flow,
+};
+use ory_kratos_client_wasm::models::ui_node::GroupEnum;
+use ory_kratos_client_wasm::models::{self, UiContainer};
 
-#[component]
-pub fn SignIn() -> Element {
-  let create_flow = use_resource(move || async move {
+/// Query arguments of `/sign-in`, forwarded to `create_browser_login_flow`.
+///
+/// Empty strings are treated as absent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoginParams {
+  pub return_to: String,
+  pub refresh: bool,
+  pub aal: String,
+  pub login_challenge: String,
+  pub organization: String,
+  pub via: String,
+}
+
+impl From<LoginParams> for Route {
+  fn from(params: LoginParams) -> Self {
+    Route::SignIn {
+      return_to: params.return_to,
+      refresh: params.refresh,
+      aal: params.aal,
+      login_challenge: params.login_challenge,
+      organization: params.organization,
+      via: params.via,
+    }
+  }
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+  if value.is_empty() { None } else { Some(value) }
+}
+
+impl SelfServiceFlow for models::LoginFlow {
+  type Params = LoginParams;
+  type CreateError = CreateBrowserLoginFlowError;
+  type GetError = GetLoginFlowError;
+  type Body = models::UpdateLoginFlowBody;
+  type UpdateError = UpdateLoginFlowError;
+
+  const TITLE: &'static str = "Sign In";
+  const NAME: &'static str = "LoginFlow";
+
+  async fn create(kratos: &Kratos, params: &LoginParams) -> Result<Self, Error<Self::CreateError>> {
     create_browser_login_flow(
-      &Configuration::create(),
-      None,
-      None,
-      None,
-      None,
-      None,
-      None,
+      kratos,
+      params.refresh.then_some(true),
+      non_empty(&params.aal),
+      non_empty(&params.return_to),
       None,
+      non_empty(&params.login_challenge),
+      non_empty(&params.organization),
+      non_empty(&params.via),
       None,
     )
     .await
-  });
-
-  // refresh: Option<bool>, aal: Option<&str>, return_to: Option<&str>, cookie: Option<&str>,
-  // login_challenge: Option<&str>, organization: Option<&str>, via: Option<&str>
-
-  return match &*create_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Sign In" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Don't have an account? "
-                Link {
-                  to: Route::SignUp {},
-                  class: "link-primary link-hover",
-                  "Get started →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(ory_kratos_client_wasm::apis::Error::ResponseError(res)) => rsx! {
-        {res.to_owned().view_response_content()}
-      },
-
-      Err(err) => {
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get RegistrationFlow! Error:" }
-          p { "{err:#?}" }
-        }
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    get_login_flow(kratos, id, None).await
+  }
+
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    match update_login_flow(kratos, &flow.id, body, None, None).await {
+      Ok(res) => Ok(Submitted::Done {
+        continue_with: res.continue_with.unwrap_or_default(),
+        return_to: flow.return_to.to_owned(),
+        next: Route::Home {},
+      }),
+      Err(err) => submitted(err, |entity| match entity {
+        UpdateLoginFlowError::Status400(flow) => Ok(Submitted::Flow(flow)),
+        UpdateLoginFlowError::Status410(_) => Ok(Submitted::Expired),
+        UpdateLoginFlowError::Status422(res) => Ok(Submitted::Redirect(
+          res.redirect_browser_to.unwrap_or_default(),
+        )),
+        entity => Err(Box::new(entity)),
+      }),
+    }
+  }
+
+  fn restart(params: &LoginParams) -> Route {
+    params.to_owned().into()
+  }
+
+  fn ui(&self) -> &UiContainer {
+    &self.ui
+  }
+
+  fn footer(i18n: &I18n) -> Element {
+    rsx! {
+      p { class: "text-sm leading-6",
+        {i18n.t("Don't have an account? ")}
+        Link { to: Route::SignUp {}, class: "link-primary link-hover", {i18n.t("Get started →")} }
       }
-    },
-    None => rsx! {},
+    }
+  }
+}
+
+#[component]
+pub fn SignIn(
+  return_to: String,
+  refresh: bool,
+  aal: String,
+  login_challenge: String,
+  organization: String,
+  via: String,
+) -> Element {
+  let params = LoginParams {
+    return_to,
+    refresh,
+    aal,
+    login_challenge,
+    organization,
+    via,
   };
+
+  rsx! {
+    FlowPage::<models::LoginFlow> { params }
+  }
 }
 
 #[component]
 pub fn LoginFlow(flow: String) -> Element {
-  let get_flow = use_resource(move || {
-    let id = flow.to_owned();
-    async move { get_login_flow(&Configuration::create(), &id, None).await }
-  });
-
-  return match &*get_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Sign In" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Don't have an account? "
-                Link {
-                  to: Route::SignUp {},
-                  class: "link-primary link-hover",
-                  "Get started →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(err) => {
-        navigator().replace(Route::SignIn {});
-        rsx! {
-          p { "Failed to get LoginFlow! Error: {err:?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+  rsx! {
+    FlowPage::<models::LoginFlow> { flow }
+  }
+}
+
+/// A login flow with `aal=aal2`, limited to second factor methods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecondFactorFlow(models::LoginFlow);
+
+impl SecondFactorFlow {
+  fn new(mut flow: models::LoginFlow) -> Self {
+    flow.ui.nodes.retain(|node| {
+      matches!(
+        node.group,
+        GroupEnum::Default | GroupEnum::Totp | GroupEnum::Webauthn | GroupEnum::LookupSecret
+      )
+    });
+    SecondFactorFlow(flow)
+  }
+}
+
+impl SelfServiceFlow for SecondFactorFlow {
+  type Params = String;
+  type CreateError = CreateBrowserLoginFlowError;
+  type GetError = GetLoginFlowError;
+  type Body = models::UpdateLoginFlowBody;
+  type UpdateError = UpdateLoginFlowError;
+
+  const TITLE: &'static str = "Two-Factor Authentication";
+  const NAME: &'static str = "LoginFlow";
+
+  async fn create(kratos: &Kratos, return_to: &String) -> Result<Self, Error<Self::CreateError>> {
+    let params = LoginParams {
+      return_to: return_to.to_owned(),
+      aal: "aal2".to_owned(),
+      ..Default::default()
+    };
+    models::LoginFlow::create(kratos, &params)
+      .await
+      .map(SecondFactorFlow::new)
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    models::LoginFlow::get(kratos, id)
+      .await
+      .map(SecondFactorFlow::new)
+  }
+
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    models::LoginFlow::update(kratos, &flow.0, body)
+      .await
+      .map(|submitted| submitted.map(SecondFactorFlow::new))
+  }
+
+  fn restart(return_to: &String) -> Route {
+    Route::SecondFactor {
+      return_to: return_to.to_owned(),
+    }
+  }
+
+  fn ui(&self) -> &UiContainer {
+    &self.0.ui
+  }
+}
+
+/// Steps the session up to AAL2, then returns to `return_to`.
+#[component]
+pub fn SecondFactor(return_to: String) -> Element {
+  rsx! {
+    FlowPage::<SecondFactorFlow> { params: return_to }
+  }
 }
@@ -2,8 +2,16 @@ mod error;
 pub use error::PageNotFound;
 pub use error::ServerError;
 
+mod home;
+pub use home::Home;
+
+mod consent;
+pub use consent::Consent;
+
 mod login;
 pub use login::LoginFlow;
+pub use login::LoginParams;
+pub use login::SecondFactor;
 pub use login::SignIn;
 
 mod register;
@@ -24,3 +32,6 @@ pub use recovery::RecoveryFlow;
 
 mod session;
 pub use session::SessionInfo;
+
+mod sessions;
+pub use sessions::Sessions;
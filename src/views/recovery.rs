@@ -1,67 +1,73 @@
-use crate::components::{DisplayError, FormBuilder};
-use crate::{Configuration, Create, Route};
-use dioxus::logger::tracing::{debug, error};
+use crate::Route;
+use crate::components::{FlowPage, SelfServiceFlow, Submitted, submitted};
+use crate::kratos::Kratos;
 use dioxus::prelude::*;
-use ory_kratos_client_wasm::apis::frontend_api::{create_browser_recovery_flow, get_recovery_flow};
+use ory_kratos_client_wasm::apis::Error;
+use ory_kratos_client_wasm::apis::frontend_api::{
+  CreateBrowserRecoveryFlowError, GetRecoveryFlowError, UpdateRecoveryFlowError,
+  create_browser_recovery_flow, get_recovery_flow, update_recovery_flow,
+};
+use ory_kratos_client_wasm::models::{self, ContinueWith, UiContainer};
 
-#[component]
-pub fn AccountRecovery() -> Element {
-  let create_flow = use_resource(move || async move {
-    create_browser_recovery_flow(&Configuration::create(), None).await
-  });
+impl SelfServiceFlow for models::RecoveryFlow {
+  type Params = ();
+  type CreateError = CreateBrowserRecoveryFlowError;
+  type GetError = GetRecoveryFlowError;
+  type Body = models::UpdateRecoveryFlowBody;
+  type UpdateError = UpdateRecoveryFlowError;
+
+  const TITLE: &'static str = "Account Recovery";
+  const NAME: &'static str = "RecoveryFlow";
+
+  async fn create(kratos: &Kratos, _: &()) -> Result<Self, Error<Self::CreateError>> {
+    create_browser_recovery_flow(kratos, None).await
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    get_recovery_flow(kratos, id, None).await
+  }
+
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    match update_recovery_flow(kratos, &flow.id, body, None, None).await {
+      Ok(flow) => Ok(Submitted::Flow(flow)),
+      Err(err) => submitted(err, |entity| match entity {
+        UpdateRecoveryFlowError::Status400(flow) => Ok(Submitted::Flow(flow)),
+        UpdateRecoveryFlowError::Status410(_) => Ok(Submitted::Expired),
+        UpdateRecoveryFlowError::Status422(res) => Ok(Submitted::Redirect(
+          res.redirect_browser_to.unwrap_or_default(),
+        )),
+        entity => Err(Box::new(entity)),
+      }),
+    }
+  }
 
-  return match &*create_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Account Recovery" }
-          div { class: "mx-auto w-full max-w-lg",
-            FormBuilder { ui: *res.ui.to_owned() }
-          }
-        }
-      }
-      Err(ory_kratos_client_wasm::apis::Error::ResponseError(res)) => rsx! {
-        {res.to_owned().view_response_content()}
-      },
+  fn restart(_: &()) -> Route {
+    Route::AccountRecovery {}
+  }
 
-      Err(err) => {
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get RegistrationFlow! Error:" }
-          p { "{err:#?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+  fn ui(&self) -> &UiContainer {
+    &self.ui
+  }
+
+  fn continue_with(&self) -> &[ContinueWith] {
+    self.continue_with.as_deref().unwrap_or_default()
+  }
 }
 
 #[component]
-pub fn RecoveryFlow(flow: String) -> Element {
-  let get_flow = use_resource(move || {
-    let id = flow.to_owned();
-    async move { get_recovery_flow(&Configuration::create(), &id, None).await }
-  });
+pub fn AccountRecovery() -> Element {
+  rsx! {
+    FlowPage::<models::RecoveryFlow> {}
+  }
+}
 
-  return match &*get_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Account Recovery" }
-          div { class: "mx-auto w-full max-w-lg",
-            FormBuilder { ui: *res.ui.to_owned() }
-          }
-        }
-      }
-      Err(err) => {
-        navigator().replace(Route::SignUp {});
-        rsx! {
-          p { "Failed to get RecoveryFlow! Error: {err:?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+#[component]
+pub fn RecoveryFlow(flow: String) -> Element {
+  rsx! {
+    FlowPage::<models::RecoveryFlow> { flow }
+  }
 }
@@ -1,90 +1,95 @@
-use crate::components::{DisplayError, FormBuilder};
-use crate::{Configuration, Create, Route};
-use dioxus::logger::tracing::{debug, error};
+use crate::Route;
+use crate::components::{FlowPage, SelfServiceFlow, Submitted, submitted};
+use crate::i18n::I18n;
+use crate::kratos::Kratos;
 use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::Error;
 use ory_kratos_client_wasm::apis::frontend_api::{
-  create_browser_registration_flow, get_registration_flow,
+  CreateBrowserRegistrationFlowError, GetRegistrationFlowError, UpdateRegistrationFlowError,
+  create_browser_registration_flow, get_registration_flow, update_registration_flow,
 };
+use ory_kratos_client_wasm::models::{RegistrationFlow, UiContainer, UpdateRegistrationFlowBody};
 
-#[component]
-pub fn SignUp() -> Element {
-  let create_flow = use_resource(move || async move {
-    create_browser_registration_flow(&Configuration::create(), None, None, None, None, None).await
-  });
+impl SelfServiceFlow for RegistrationFlow {
+  type Params = ();
+  type CreateError = CreateBrowserRegistrationFlowError;
+  type GetError = GetRegistrationFlowError;
+  type Body = UpdateRegistrationFlowBody;
+  type UpdateError = UpdateRegistrationFlowError;
 
-  return match &*create_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Sign Up" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Already have an account? "
-                Link {
-                  to: Route::SignIn {},
-                  class: "link-primary link-hover",
-                  "Login →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(ory_kratos_client_wasm::apis::Error::ResponseError(res)) => rsx! {
-        {res.to_owned().view_response_content()}
-      },
+  const TITLE: &'static str = "Sign Up";
+  const NAME: &'static str = "RegistrationFlow";
+
+  async fn create(kratos: &Kratos, _: &()) -> Result<Self, Error<Self::CreateError>> {
+    create_browser_registration_flow(kratos, None, None, None, None, None).await
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    get_registration_flow(kratos, id, None).await
+  }
+
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    match update_registration_flow(kratos, &flow.id, body, None).await {
+      // Without the `session` hook the new identity still has to sign in.
+      Ok(res) => Ok(Submitted::Done {
+        continue_with: res.continue_with.unwrap_or_default(),
+        return_to: flow.return_to.to_owned(),
+        next: if res.session.is_some() {
+          Route::Home {}
+        } else {
+          Route::sign_in()
+        },
+      }),
+      Err(err) => submitted(err, |entity| match entity {
+        UpdateRegistrationFlowError::Status400(flow) => Ok(Submitted::Flow(flow)),
+        UpdateRegistrationFlowError::Status410(_) => Ok(Submitted::Expired),
+        UpdateRegistrationFlowError::Status422(res) => Ok(Submitted::Redirect(
+          res.redirect_browser_to.unwrap_or_default(),
+        )),
+        entity => Err(Box::new(entity)),
+      }),
+    }
+  }
+
+  fn restart(_: &()) -> Route {
+    Route::SignUp {}
+  }
+
+  fn ui(&self) -> &UiContainer {
+    &self.ui
+  }
 
-      Err(err) => {
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get RegistrationFlow! Error:" }
-          p { "{err:#?}" }
-        }
+  fn identity_schema(&self, default: &str) -> Option<String> {
+    self
+      .identity_schema
+      .to_owned()
+      .or_else(|| Some(default.to_owned()))
+  }
+
+  fn footer(i18n: &I18n) -> Element {
+    rsx! {
+      p { class: "text-sm leading-6",
+        {i18n.t("Already have an account? ")}
+        Link { to: Route::sign_in(), class: "link-primary link-hover", {i18n.t("Login →")} }
       }
-    },
-    None => rsx! {},
-  };
+    }
+  }
 }
 
 #[component]
-pub fn RegisterFlow(flow: String) -> Element {
-  let get_flow = use_resource(move || {
-    let id = flow.to_owned();
-    async move { get_registration_flow(&Configuration::create(), &id, None).await }
-  });
+pub fn SignUp() -> Element {
+  rsx! {
+    FlowPage::<RegistrationFlow> {}
+  }
+}
 
-  return match &*get_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Sign Up" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Already have an account? "
-                Link {
-                  to: Route::SignIn {},
-                  class: "link-primary link-hover",
-                  "Login →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(err) => {
-        navigator().replace(Route::SignUp {});
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get RegistrationFlow! Error:\n {err:#?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+#[component]
+pub fn RegisterFlow(flow: String) -> Element {
+  rsx! {
+    FlowPage::<RegistrationFlow> { flow }
+  }
 }
@@ -1,65 +1,292 @@
 use std::format;
 
-use crate::components::DisplayError;
-use crate::{Configuration, Create};
+use crate::Route;
+use crate::components::{DisplayError, Timestamp};
+use crate::i18n::use_i18n;
+use crate::identity_schema;
+use crate::kratos::use_kratos;
+use crate::views::sessions::method_label;
 use dioxus::logger::tracing::error;
 use dioxus::prelude::*;
 use ory_kratos_client_wasm::apis::frontend_api::to_session;
+use ory_kratos_client_wasm::models::Identity;
+use ory_kratos_client_wasm::models::identity::StateEnum;
+use ory_kratos_client_wasm::models::verifiable_identity_address::ViaEnum;
+use serde_json::Value;
+
+/// A trait value as text, with arrays joined by commas.
+fn trait_text(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(value) => value.to_owned(),
+    Value::Array(values) => values.iter().map(trait_text).collect::<Vec<_>>().join(", "),
+    value => value.to_string(),
+  }
+}
+
+/// Flattens `traits` into labelled rows, labelled by the `title`s of the identity `schema` where
+/// it has them and by their dotted path otherwise.
+fn trait_rows(
+  traits: &Value,
+  schema: Option<&Value>,
+  path: &str,
+  rows: &mut Vec<(String, String)>,
+) {
+  let Value::Object(traits) = traits else {
+    rows.push((path.to_owned(), trait_text(traits)));
+    return;
+  };
+
+  for (key, value) in traits {
+    let path = if path.is_empty() {
+      key.to_owned()
+    } else {
+      format!("{path}.{key}")
+    };
+    let property = schema.and_then(|schema| schema.get("properties")?.get(key));
+
+    if value.is_object() {
+      trait_rows(value, property, &path, rows);
+    } else {
+      let label = property
+        .and_then(|property| property.get("title")?.as_str())
+        .map(str::to_owned)
+        .unwrap_or_else(|| path.to_owned());
+      rows.push((label, trait_text(value)));
+    }
+  }
+}
+
+fn state_label(state: StateEnum) -> &'static str {
+  match state {
+    StateEnum::Active => "Active",
+    StateEnum::Inactive => "Inactive",
+  }
+}
+
+fn via_label(via: ViaEnum) -> &'static str {
+  match via {
+    ViaEnum::Email => "Email",
+    ViaEnum::Sms => "SMS",
+  }
+}
+
+#[component]
+fn IdentityPanel(identity: Identity) -> Element {
+  let i18n = use_i18n();
+  let kratos = use_kratos();
+  let schema_id = identity.schema_id.to_owned();
+  let schema = use_resource(use_reactive!(|schema_id| {
+    let kratos = kratos.to_owned();
+    async move { identity_schema::fetch(&kratos, &schema_id).await }
+  }));
+
+  // Shown untitled until the schema has loaded, or if it can't be.
+  let schema = schema.read().to_owned().flatten();
+  let mut traits = Vec::new();
+  if let Some(values) = &identity.traits {
+    let schema = schema.as_ref().and_then(identity_schema::traits);
+    trait_rows(values, schema, "", &mut traits);
+  }
+
+  let mut credentials: Vec<_> = identity.credentials.iter().flatten().collect();
+  credentials.sort_by_key(|(kind, _)| kind.to_owned());
+
+  rsx! {
+    div { class: "mx-auto w-full max-w-lg",
+      div { class: "mt-10",
+        label { class: "text-lg",
+          {i18n.t("Identity")}
+          table { class: "table",
+            tbody {
+              tr {
+                th { {i18n.t("ID")} }
+                td { {identity.id.to_owned()} }
+              }
+              if let Some(state) = &identity.state {
+                tr {
+                  th { {i18n.t("State")} }
+                  td { {i18n.t(state_label(*state))} }
+                }
+              }
+              if let Some(state_changed_at) = &identity.state_changed_at {
+                tr {
+                  th { {i18n.t("State Changed")} }
+                  td { Timestamp { value: state_changed_at.to_owned() } }
+                }
+              }
+              if let Some(created_at) = &identity.created_at {
+                tr {
+                  th { {i18n.t("Created")} }
+                  td { Timestamp { value: created_at.to_owned() } }
+                }
+              }
+              if let Some(updated_at) = &identity.updated_at {
+                tr {
+                  th { {i18n.t("Updated")} }
+                  td { Timestamp { value: updated_at.to_owned() } }
+                }
+              }
+              for (label, value) in traits {
+                tr {
+                  th { {label} }
+                  td { {value} }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+    if let Some(addresses) = &identity.verifiable_addresses {
+      div { class: "mx-auto w-full max-w-lg",
+        div { class: "mt-10",
+          label { class: "text-lg",
+            {i18n.t("Verifiable Addresses")}
+            table { class: "table",
+              tbody {
+                for address in addresses {
+                  tr {
+                    td { "{address.value}" }
+                    td { {i18n.t(via_label(address.via))} }
+                    td {
+                      if address.verified {
+                        span { class: "badge badge-success", {i18n.t("Verified")} }
+                        if let Some(verified_at) = &address.verified_at {
+                          p { class: "text-xs", Timestamp { value: verified_at.to_owned() } }
+                        }
+                      } else {
+                        span { class: "badge badge-warning", {i18n.t("Unverified")} }
+                        Link {
+                          to: Route::Verify {},
+                          class: "link-primary link-hover text-sm ml-2",
+                          {i18n.t("Verify now")}
+                        }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+    if let Some(addresses) = &identity.recovery_addresses {
+      div { class: "mx-auto w-full max-w-lg",
+        div { class: "mt-10",
+          label { class: "text-lg",
+            {i18n.t("Recovery Addresses")}
+            table { class: "table",
+              tbody {
+                for address in addresses {
+                  tr {
+                    td { "{address.value}" }
+                    td { "{address.via}" }
+                  }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+    if !credentials.is_empty() {
+      div { class: "mx-auto w-full max-w-lg",
+        div { class: "mt-10",
+          label { class: "text-lg",
+            {i18n.t("Credentials")}
+            table { class: "table",
+              thead {
+                th { {i18n.t("Type")} }
+                th { {i18n.t("Identifiers")} }
+                th { {i18n.t("Updated")} }
+              }
+              tbody {
+                for (kind, credential) in credentials {
+                  tr {
+                    td { "{kind}" }
+                    td { {credential.identifiers.as_deref().unwrap_or_default().join(", ")} }
+                    td {
+                      if let Some(updated_at) = &credential.updated_at {
+                        Timestamp { value: updated_at.to_owned() }
+                      }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+}
 
 #[component]
 pub fn SessionInfo() -> Element {
-  let create_flow: Resource<Result<_, ory_kratos_client_wasm::apis::Error<_>>> = use_resource(
-    move || async move { to_session(&Configuration::create(), None, None, None).await },
-  );
+  let kratos = use_kratos();
+  let i18n = use_i18n();
+  let create_flow: Resource<Result<_, ory_kratos_client_wasm::apis::Error<_>>> =
+    use_resource(move || {
+      let kratos = kratos.to_owned();
+      async move { to_session(&kratos, None, None, None).await }
+    });
 
   return match &*create_flow.read() {
     Some(new_flow) => match new_flow {
       Ok(res) => {
         rsx! {
-          h1 { class: "text-center text-2xl", "Session Info" }
+          h1 { class: "text-center text-2xl", {i18n.t("Session Info")} }
           div { class: "mx-auto w-full max-w-lg",
             div { class: "mt-10",
               label { class: "text-lg",
-                "Basic Info"
+                {i18n.t("Basic Info")}
                 table { class: "table",
                   tbody {
                     tr {
-                      th { "ID" }
+                      th { {i18n.t("ID")} }
                       td { {res.id.clone()} }
                     }
                     if let Some(active) = res.active {
                       tr {
-                        th { "Active" }
-                        td { {active.to_string()} }
+                        th { {i18n.t("Active")} }
+                        td { {i18n.t(if active { "Yes" } else { "No" })} }
                       }
                     }
                     if let Some(authenticated_at) = &res.authenticated_at {
                       tr {
-                        th { "Authenticated" }
-                        td { {authenticated_at.clone()} }
+                        th { {i18n.t("Authenticated")} }
+                        td {
+                          Timestamp { value: authenticated_at.to_owned() }
+                        }
                       }
                     }
                     if let Some(authenticator_assurance_level) = &res.authenticator_assurance_level {
                       tr {
-                        th { "Authenticator Assurance Level" }
+                        th { {i18n.t("Authenticator Assurance Level")} }
                         td { {authenticator_assurance_level.to_string()} }
                       }
                     }
                     if let Some(expires_at) = &res.expires_at {
                       tr {
-                        th { "Expires" }
-                        td { {expires_at.clone()} }
+                        th { {i18n.t("Expires")} }
+                        td {
+                          Timestamp { value: expires_at.to_owned() }
+                        }
                       }
                     }
                     if let Some(issued_at) = &res.issued_at {
                       tr {
-                        th { "Issued" }
-                        td { {issued_at.clone()} }
+                        th { {i18n.t("Issued")} }
+                        td {
+                          Timestamp { value: issued_at.to_owned() }
+                        }
                       }
                     }
                     if let Some(tokenized) = &res.tokenized {
                       tr {
-                        th { "Tokenized" }
+                        th { {i18n.t("Tokenized")} }
                         td { {tokenized.clone()} }
                       }
                     }
@@ -71,14 +298,14 @@ pub fn SessionInfo() -> Element {
           div { class: "mx-auto w-full max-w-lg",
             div { class: "mt-10",
               label { class: "text-lg",
-                "Authentication Methods"
+                {i18n.t("Authentication Methods")}
                 table { class: "table",
                   thead {
-                    th { "AAL" }
-                    th { "Completed At" }
-                    th { "Method" }
-                    th { "Organization" }
-                    th { "Provider" }
+                    th { {i18n.t("AAL")} }
+                    th { {i18n.t("Completed At")} }
+                    th { {i18n.t("Method")} }
+                    th { {i18n.t("Organization")} }
+                    th { {i18n.t("Provider")} }
                   }
                   if let Some(authentication_methods) = &res.authentication_methods {
                     tbody {
@@ -91,14 +318,13 @@ pub fn SessionInfo() -> Element {
                             }
                           }
                           td {
-                            match &method.completed_at {
-                                Some(completed_at) => completed_at,
-                                None => "",
+                            if let Some(completed_at) = &method.completed_at {
+                              Timestamp { value: completed_at.to_owned() }
                             }
                           }
                           td {
                             match method.method {
-                                Some(method) => format!("{method:?}"),
+                                Some(method) => i18n.t(method_label(method)),
                                 None => "".to_string(),
                             }
                           }
@@ -125,13 +351,13 @@ pub fn SessionInfo() -> Element {
           div { class: "mx-auto w-full max-w-lg",
             div { class: "mt-10",
               label { class: "text-lg",
-                "Devices"
+                {i18n.t("Devices")}
                 table { class: "table",
                   thead {
-                    th { "ID" }
-                    th { "IP Address" }
-                    th { "Location" }
-                    th { "User Agent" }
+                    th { {i18n.t("ID")} }
+                    th { {i18n.t("IP Address")} }
+                    th { {i18n.t("Location")} }
+                    th { {i18n.t("User Agent")} }
                   }
                   if let Some(devices) = &res.devices {
                     tbody {
@@ -165,15 +391,8 @@ pub fn SessionInfo() -> Element {
             }
           }
 
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              label { class: "text-lg",
-                "Identity"
-                if let Some(identity) = &res.identity {
-                  code { {format!("{identity:#?}")} }
-                }
-              }
-            }
+          if let Some(identity) = &res.identity {
+            IdentityPanel { identity: *identity.to_owned() }
           }
         }
       }
@@ -184,7 +403,7 @@ pub fn SessionInfo() -> Element {
       Err(err) => {
         error!("{err:#?}");
         rsx! {
-          p { "Failed to get RegistrationFlow! Error:" }
+          p { {i18n.t("Failed to get Session! Error:")} }
           p { "{err:#?}" }
         }
       }
@@ -192,67 +411,3 @@ pub fn SessionInfo() -> Element {
     None => rsx! {},
   };
 }
-
-// div { class: "mx-auto w-full max-w-lg",
-//   div { class: "mt-10",
-//     label { class: "text-lg",
-//       "Identity"
-//       table { class: "table",
-//         thead {
-//           th { "ID" }
-//           th { "created_at" }
-//           th { "credentials" }
-//           th { "metadata_admin" }
-//           th { "metadata_public" }
-//           th { "organization_id" }
-//           th { "recovery_addresses" }
-//           th { "schema_id" }
-//           th { "schema_url" }
-//           th { "state" }
-//           th { "state_changed_at" }
-//           th { "traits" }
-//           th { "updated_at" }
-//           th { "verifiable_addresses" }
-//         }
-//         if let Some(*identity) = res.identity {
-//           tbody {
-//             for id in identity {
-//               tr {
-//                 td {
-//                   match id.aal {
-//                       Some(aal) => aal.to_string(),
-//                       None => "".to_string(),
-//                   }
-//                 }
-//                 td {
-//                   match &id.completed_at {
-//                       Some(completed_at) => completed_at.clone(),
-//                       None => "".to_string(),
-//                   }
-//                 }
-//                 td {
-//                   match id.method {
-//                       Some(method) => format!("{method:?}"),
-//                       None => "".to_string(),
-//                   }
-//                 }
-//                 td {
-//                   match &id.organization {
-//                       Some(organization) => organization.clone(),
-//                       None => "".to_string(),
-//                   }
-//                 }
-//                 td {
-//                   match &id.provider {
-//                       Some(provider) => provider.clone(),
-//                       None => "".to_string(),
-//                   }
-//                 }
-//               }
-//             }
-//           }
-//         }
-//       }
-//     }
-//   }
-// }
@@ -0,0 +1,193 @@
+use crate::components::Timestamp;
+use crate::i18n::use_i18n;
+use crate::kratos::use_kratos;
+use crate::session::use_session;
+use dioxus::logger::tracing::error;
+use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::frontend_api::{
+  disable_my_other_sessions, disable_my_session, list_my_sessions,
+};
+use ory_kratos_client_wasm::models::Session;
+use ory_kratos_client_wasm::models::session_authentication_method::MethodEnum;
+
+/// Name of an authentication method, matching the form's group headings.
+pub(super) fn method_label(method: MethodEnum) -> &'static str {
+  match method {
+    MethodEnum::Password => "Password",
+    MethodEnum::Oidc => "Social Sign-In",
+    MethodEnum::Totp => "Authenticator App",
+    MethodEnum::LookupSecret => "Recovery Codes",
+    MethodEnum::Webauthn => "Web Authentication",
+    MethodEnum::Code => "Code",
+    MethodEnum::Passkey => "Passkey",
+    MethodEnum::Profile => "Profile",
+    MethodEnum::Saml => "SAML",
+    MethodEnum::LinkRecovery => "Recovery Link",
+    MethodEnum::CodeRecovery => "Recovery Code",
+  }
+}
+
+#[component]
+fn SessionCard(session: Session, onrevoke: Option<EventHandler<String>>) -> Element {
+  let i18n = use_i18n();
+  let id = session.id.to_owned();
+
+  rsx! {
+    div { class: "card bg-base-200 my-4",
+      div { class: "card-body",
+        h2 { class: "card-title",
+          if onrevoke.is_none() {
+            {i18n.t("This device")}
+          } else {
+            {i18n.t("Other session")}
+          }
+          if let Some(aal) = session.authenticator_assurance_level {
+            span { class: "badge badge-outline", {aal.to_string()} }
+          }
+        }
+        table { class: "table table-sm",
+          tbody {
+            if let Some(authenticated_at) = &session.authenticated_at {
+              tr {
+                th { {i18n.t("Authenticated")} }
+                td {
+                  Timestamp { value: authenticated_at.to_owned() }
+                }
+              }
+            }
+            if let Some(expires_at) = &session.expires_at {
+              tr {
+                th { {i18n.t("Expires")} }
+                td {
+                  Timestamp { value: expires_at.to_owned() }
+                }
+              }
+            }
+            if let Some(methods) = &session.authentication_methods {
+              tr {
+                th { {i18n.t("Methods")} }
+                td {
+                  for method in methods {
+                    span { class: "badge badge-ghost mr-1",
+                      {method.method.map(|method| i18n.t(method_label(method))).unwrap_or_default()}
+                      if let Some(provider) = &method.provider {
+                        " ({provider})"
+                      }
+                    }
+                  }
+                }
+              }
+            }
+          }
+        }
+        if let Some(devices) = &session.devices {
+          table { class: "table table-sm",
+            thead {
+              tr {
+                th { {i18n.t("IP Address")} }
+                th { {i18n.t("Location")} }
+                th { {i18n.t("User Agent")} }
+              }
+            }
+            tbody {
+              for device in devices {
+                tr { key: "{device.id}",
+                  td { {device.ip_address.to_owned().unwrap_or_default()} }
+                  td { {device.location.to_owned().unwrap_or_default()} }
+                  td { class: "break-all", {device.user_agent.to_owned().unwrap_or_default()} }
+                }
+              }
+            }
+          }
+        }
+        if let Some(onrevoke) = onrevoke {
+          div { class: "card-actions justify-end",
+            button {
+              class: "btn btn-error btn-outline btn-sm",
+              onclick: move |_| onrevoke.call(id.to_owned()),
+              {i18n.t("Revoke")}
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+/// Lists the identity's sessions, with revoking a single other session or all of them.
+#[component]
+pub fn Sessions() -> Element {
+  let kratos = use_kratos();
+  let i18n = use_i18n();
+  let current = use_session().session();
+  let mut failure = use_signal(|| None::<String>);
+
+  let list_kratos = kratos.to_owned();
+  // `list_my_sessions` leaves out the current session.
+  let mut others = use_resource(move || {
+    let kratos = list_kratos.to_owned();
+    async move { list_my_sessions(&kratos, None, None, None, None, None, None).await }
+  });
+
+  let revoke_kratos = kratos.to_owned();
+  let revoke = move |id: String| {
+    let kratos = revoke_kratos.to_owned();
+    spawn(async move {
+      match disable_my_session(&kratos, &id, None, None).await {
+        Ok(()) => others.restart(),
+        Err(err) => {
+          error!("{err:#?}");
+          failure.set(Some(i18n.t("Failed to revoke the session.")));
+        }
+      }
+    });
+  };
+
+  let revoke_all = move |_: MouseEvent| {
+    let kratos = kratos.to_owned();
+    spawn(async move {
+      match disable_my_other_sessions(&kratos, None, None).await {
+        Ok(_) => others.restart(),
+        Err(err) => {
+          error!("{err:#?}");
+          failure.set(Some(i18n.t("Failed to sign out the other sessions.")));
+        }
+      }
+    });
+  };
+
+  rsx! {
+    h1 { class: "text-center text-2xl", {i18n.t("Devices & Sessions")} }
+    div { class: "mx-auto w-full max-w-lg",
+      div { class: "mt-10",
+        if let Some(failure) = failure() {
+          div { role: "alert", class: "alert alert-error",
+            span { {failure} }
+          }
+        }
+        if let Some(session) = current {
+          SessionCard { session }
+        }
+        match &*others.read() {
+            Some(Ok(sessions)) if sessions.is_empty() => rsx! {
+              p { class: "text-sm my-4", {i18n.t("You are not signed in anywhere else.")} }
+            },
+            Some(Ok(sessions)) => rsx! {
+              for session in sessions.iter().cloned() {
+                SessionCard { key: "{session.id}", session, onrevoke: revoke.clone() }
+              }
+              button { class: "btn btn-error w-full my-4", onclick: revoke_all, {i18n.t("Sign out everywhere else")} }
+            },
+            Some(Err(err)) => {
+                error!("{err:#?}");
+                rsx! {
+                  p { {i18n.t("Failed to list sessions! Error:")} }
+                  p { "{err:#?}" }
+                }
+            }
+            None => rsx! {},
+        }
+      }
+    }
+  }
+}
@@ -1,66 +1,94 @@
-use crate::components::{DisplayError, FormBuilder};
-use crate::{Configuration, Create, Route};
-use dioxus::logger::tracing::{debug, error};
+use crate::Route;
+use crate::browser::current_url;
+use crate::components::{FlowPage, SelfServiceFlow, Submitted, submitted};
+use crate::kratos::Kratos;
+use crate::views::LoginParams;
 use dioxus::prelude::*;
-use ory_kratos_client_wasm::apis::frontend_api::{create_browser_settings_flow, get_settings_flow};
+use ory_kratos_client_wasm::apis::Error;
+use ory_kratos_client_wasm::apis::frontend_api::{
+  CreateBrowserSettingsFlowError, GetSettingsFlowError, UpdateSettingsFlowError,
+  create_browser_settings_flow, get_settings_flow, update_settings_flow,
+};
+use ory_kratos_client_wasm::models::{self, ContinueWith, UiContainer};
 
-#[component]
-pub fn Settings() -> Element {
-  let create_flow = use_resource(move || async move {
-    create_browser_settings_flow(&Configuration::create(), None, None).await
-  });
+impl SelfServiceFlow for models::SettingsFlow {
+  type Params = ();
+  type CreateError = CreateBrowserSettingsFlowError;
+  type GetError = GetSettingsFlowError;
+  type Body = models::UpdateSettingsFlowBody;
+  type UpdateError = UpdateSettingsFlowError;
+
+  const TITLE: &'static str = "User Settings";
+  const NAME: &'static str = "SettingsFlow";
+
+  async fn create(kratos: &Kratos, _: &()) -> Result<Self, Error<Self::CreateError>> {
+    create_browser_settings_flow(kratos, None, None).await
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    get_settings_flow(kratos, id, None, None).await
+  }
 
-  return match &*create_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "User Settings" }
-          div { class: "mx-auto w-full max-w-lg",
-            FormBuilder { ui: *res.ui.to_owned() }
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    match update_settings_flow(kratos, &flow.id, body, None, None).await {
+      Ok(flow) => Ok(Submitted::Flow(flow)),
+      Err(err) => submitted(err, |entity| match entity {
+        UpdateSettingsFlowError::Status400(flow) => Ok(Submitted::Flow(flow)),
+        // Privileged changes, e.g. of the password, need a recent sign in, `session_refresh_required`.
+        UpdateSettingsFlowError::Status401(_) => Ok(Submitted::Reauthenticate(
+          LoginParams {
+            return_to: current_url(),
+            refresh: true,
+            ..Default::default()
           }
+          .into(),
+        )),
+        // `session_aal2_required`, the identity has a second factor the session doesn't cover.
+        UpdateSettingsFlowError::Status403(_) => {
+          Ok(Submitted::Reauthenticate(Route::SecondFactor {
+            return_to: current_url(),
+          }))
         }
-      }
-      Err(ory_kratos_client_wasm::apis::Error::ResponseError(res)) => rsx! {
-        {res.to_owned().view_response_content()}
-      },
-      Err(err) => {
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get SeetingsFlow! Error:" }
-          p { "{err:#?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+        UpdateSettingsFlowError::Status410(_) => Ok(Submitted::Expired),
+        UpdateSettingsFlowError::Status422(res) => Ok(Submitted::Redirect(
+          res.redirect_browser_to.unwrap_or_default(),
+        )),
+        entity => Err(Box::new(entity)),
+      }),
+    }
+  }
+
+  fn restart(_: &()) -> Route {
+    Route::SessionInfo {}
+  }
+
+  fn ui(&self) -> &UiContainer {
+    &self.ui
+  }
+
+  fn identity_schema(&self, _default: &str) -> Option<String> {
+    Some(self.identity.schema_id.to_owned())
+  }
+
+  fn continue_with(&self) -> &[ContinueWith] {
+    self.continue_with.as_deref().unwrap_or_default()
+  }
 }
 
 #[component]
-pub fn SettingsFlow(flow: String) -> Element {
-  let get_flow = use_resource(move || {
-    let id = flow.to_owned();
-    async move { get_settings_flow(&Configuration::create(), &id, None, None).await }
-  });
+pub fn Settings() -> Element {
+  rsx! {
+    FlowPage::<models::SettingsFlow> {}
+  }
+}
 
-  return match &*get_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "User Settings" }
-          div { class: "mx-auto w-full max-w-lg",
-            FormBuilder { ui: *res.ui.to_owned() }
-          }
-        }
-      }
-      Err(err) => {
-        navigator().replace(Route::SessionInfo {});
-        rsx! {
-          p { "Failed to get RegistrationFlow! Error: {err:?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+#[component]
+pub fn SettingsFlow(flow: String) -> Element {
+  rsx! {
+    FlowPage::<models::SettingsFlow> { flow }
+  }
 }
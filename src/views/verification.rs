@@ -1,88 +1,76 @@
-use crate::components::{DisplayError, FormBuilder};
-use crate::{Configuration, Create, Route};
-use dioxus::logger::tracing::{debug, error};
+use crate::Route;
+use crate::components::{FlowPage, SelfServiceFlow, Submitted, submitted};
+use crate::i18n::I18n;
+use crate::kratos::Kratos;
 use dioxus::prelude::*;
+use ory_kratos_client_wasm::apis::Error;
 use ory_kratos_client_wasm::apis::frontend_api::{
-  create_browser_verification_flow, get_verification_flow,
+  CreateBrowserVerificationFlowError, GetVerificationFlowError, UpdateVerificationFlowError,
+  create_browser_verification_flow, get_verification_flow, update_verification_flow,
 };
+use ory_kratos_client_wasm::models::{self, UiContainer};
 
-#[component]
-pub fn Verify() -> Element {
-  let create_flow = use_resource(move || async move {
-    create_browser_verification_flow(&Configuration::create(), None).await
-  });
+impl SelfServiceFlow for models::VerificationFlow {
+  type Params = ();
+  type CreateError = CreateBrowserVerificationFlowError;
+  type GetError = GetVerificationFlowError;
+  type Body = models::UpdateVerificationFlowBody;
+  type UpdateError = UpdateVerificationFlowError;
 
-  return match &*create_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Account Verification" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Already have an account? "
-                Link {
-                  to: Route::SignIn {},
-                  class: "link-primary link-hover",
-                  "Login →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(ory_kratos_client_wasm::apis::Error::ResponseError(res)) => rsx! {
-        {res.to_owned().view_response_content()}
-      },
-      Err(err) => {
-        error!("{err:#?}");
-        rsx! {
-          p { "Failed to get VerificationFlow! Error:" }
-          p { "{err:#?}" }
-        }
+  const TITLE: &'static str = "Account Verification";
+  const NAME: &'static str = "VerificationFlow";
+
+  async fn create(kratos: &Kratos, _: &()) -> Result<Self, Error<Self::CreateError>> {
+    create_browser_verification_flow(kratos, None).await
+  }
+
+  async fn get(kratos: &Kratos, id: &str) -> Result<Self, Error<Self::GetError>> {
+    get_verification_flow(kratos, id, None).await
+  }
+
+  async fn update(
+    kratos: &Kratos,
+    flow: &Self,
+    body: Self::Body,
+  ) -> Result<Submitted<Self>, Error<Self::UpdateError>> {
+    match update_verification_flow(kratos, &flow.id, body, None, None).await {
+      Ok(flow) => Ok(Submitted::Flow(flow)),
+      Err(err) => submitted(err, |entity| match entity {
+        UpdateVerificationFlowError::Status400(flow) => Ok(Submitted::Flow(flow)),
+        UpdateVerificationFlowError::Status410(_) => Ok(Submitted::Expired),
+        entity => Err(Box::new(entity)),
+      }),
+    }
+  }
+
+  fn restart(_: &()) -> Route {
+    Route::Verify {}
+  }
+
+  fn ui(&self) -> &UiContainer {
+    &self.ui
+  }
+
+  fn footer(i18n: &I18n) -> Element {
+    rsx! {
+      p { class: "text-sm leading-6",
+        {i18n.t("Already have an account? ")}
+        Link { to: Route::sign_in(), class: "link-primary link-hover", {i18n.t("Login →")} }
       }
-    },
-    None => rsx! {},
-  };
+    }
+  }
 }
 
 #[component]
-pub fn VerificationFlow(flow: String) -> Element {
-  let get_flow = use_resource(move || {
-    let id = flow.to_owned();
-    async move { get_verification_flow(&Configuration::create(), &id, None).await }
-  });
+pub fn Verify() -> Element {
+  rsx! {
+    FlowPage::<models::VerificationFlow> {}
+  }
+}
 
-  return match &*get_flow.read() {
-    Some(new_flow) => match new_flow {
-      Ok(res) => {
-        debug!("{res:#?}");
-        rsx! {
-          h1 { class: "text-center text-2xl", "Account Verification" }
-          div { class: "mx-auto w-full max-w-lg",
-            div { class: "mt-10",
-              FormBuilder { ui: *res.ui.to_owned() }
-              p { class: "text-sm leading-6",
-                "Already have an account? "
-                Link {
-                  to: Route::SignIn {},
-                  class: "link-primary link-hover",
-                  "Login →"
-                }
-              }
-            }
-          }
-        }
-      }
-      Err(err) => {
-        navigator().replace(Route::Verify {});
-        rsx! {
-          p { "Failed to get VerificationFlow! Error: {err:?}" }
-        }
-      }
-    },
-    None => rsx! {},
-  };
+#[component]
+pub fn VerificationFlow(flow: String) -> Element {
+  rsx! {
+    FlowPage::<models::VerificationFlow> { flow }
+  }
 }
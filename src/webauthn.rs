@@ -0,0 +1,284 @@
+use base64::Engine;
+use base64::alphabet::URL_SAFE;
+use base64::engine::DecodePaddingMode;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use dioxus::logger::tracing::warn;
+use ory_kratos_client_wasm::models::UiNodeScriptAttributes;
+use serde::Serialize;
+use serde_json::json;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{Array, Function, JSON, Promise, Reflect, Uint8Array};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  AbortSignal, CredentialCreationOptions, CredentialRequestOptions, Document, HtmlFormElement,
+};
+
+/// WebAuthn encodes binary fields as unpadded base64url, but tolerate padding when decoding.
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+  &URL_SAFE,
+  GeneralPurposeConfig::new()
+    .with_encode_padding(false)
+    .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+enum Ceremony {
+  Create,
+  Get,
+}
+
+fn document() -> Result<Document, JsValue> {
+  web_sys::window()
+    .and_then(|window| window.document())
+    .ok_or_else(|| JsValue::from_str("Could not access window document"))
+}
+
+fn field(document: &Document, name: &str) -> Result<web_sys::Element, JsValue> {
+  document
+    .query_selector(&format!("[name=\"{name}\"]"))?
+    .ok_or_else(|| JsValue::from_str(&format!("Missing form field {name}")))
+}
+
+fn field_value(document: &Document, name: &str) -> Result<String, JsValue> {
+  Reflect::get(&JsValue::from(field(document, name)?), &"value".into())?
+    .as_string()
+    .ok_or_else(|| JsValue::from_str(&format!("Form field {name} has no value")))
+}
+
+fn get(object: &JsValue, key: &str) -> Result<JsValue, JsValue> {
+  Reflect::get(object, &key.into())
+}
+
+fn set(object: &JsValue, key: &str, value: &JsValue) -> Result<(), JsValue> {
+  Reflect::set(object, &key.into(), value).map(|_| ())
+}
+
+/// Replaces the base64url string at `object[key]` with its bytes.
+fn decode_field(object: &JsValue, key: &str) -> Result<(), JsValue> {
+  if let Some(encoded) = get(object, key)?.as_string() {
+    let bytes = BASE64URL
+      .decode(encoded)
+      .map_err(|err| JsValue::from_str(&err.to_string()))?;
+    set(object, key, &Uint8Array::from(bytes.as_slice()))?;
+  }
+  Ok(())
+}
+
+/// Decodes the ids of the credential descriptors at `public_key[key]`.
+fn decode_credentials(public_key: &JsValue, key: &str) -> Result<(), JsValue> {
+  let credentials = get(public_key, key)?;
+  if Array::is_array(&credentials) {
+    for credential in Array::from(&credentials).iter() {
+      decode_field(&credential, "id")?;
+    }
+  }
+  Ok(())
+}
+
+/// Encodes the `ArrayBuffer` at `object[key]` as base64url, or `null` if it is absent.
+fn encode_field(object: &JsValue, key: &str) -> Result<serde_json::Value, JsValue> {
+  let buffer = get(object, key)?;
+  if buffer.is_null() || buffer.is_undefined() {
+    return Ok(serde_json::Value::Null);
+  }
+  Ok(BASE64URL.encode(Uint8Array::new(&buffer).to_vec()).into())
+}
+
+/// Runs the ceremony for the `{ publicKey }` `options` and returns the credential in the JSON
+/// format Kratos expects.
+async fn ceremony(ceremony: Ceremony, options: JsValue) -> Result<String, JsValue> {
+  let public_key = get(&options, "publicKey")?;
+  decode_field(&public_key, "challenge")?;
+
+  let credentials = web_sys::window()
+    .ok_or_else(|| JsValue::from_str("Could not access window"))?
+    .navigator()
+    .credentials();
+
+  let (promise, response_fields): (_, &[&str]) = match ceremony {
+    Ceremony::Create => {
+      decode_field(&get(&public_key, "user")?, "id")?;
+      decode_credentials(&public_key, "excludeCredentials")?;
+      (
+        credentials.create_with_options(options.unchecked_ref::<CredentialCreationOptions>())?,
+        &["attestationObject", "clientDataJSON"],
+      )
+    }
+    Ceremony::Get => {
+      decode_credentials(&public_key, "allowCredentials")?;
+      (
+        credentials.get_with_options(options.unchecked_ref::<CredentialRequestOptions>())?,
+        &[
+          "authenticatorData",
+          "clientDataJSON",
+          "signature",
+          "userHandle",
+        ],
+      )
+    }
+  };
+
+  let credential = JsFuture::from(promise).await?;
+  let response = get(&credential, "response")?;
+
+  let mut encoded_response = serde_json::Map::new();
+  for key in response_fields {
+    encoded_response.insert(key.to_string(), encode_field(&response, key)?);
+  }
+
+  Ok(
+    json!({
+      "id": get(&credential, "id")?.as_string(),
+      "rawId": encode_field(&credential, "rawId")?,
+      "type": get(&credential, "type")?.as_string(),
+      "response": encoded_response,
+    })
+    .to_string(),
+  )
+}
+
+/// Passkey registration data, `{ credentialOptions, displayNameFieldName }`, with the user's
+/// name taken from the identifier field of the form.
+fn passkey_create_options(document: &Document) -> Result<JsValue, JsValue> {
+  let data = JSON::parse(&field_value(document, "passkey_create_data")?)?;
+  let options = get(&data, "credentialOptions")?;
+
+  if let Some(name_field) = get(&data, "displayNameFieldName")?.as_string()
+    && let Ok(name) = field_value(document, &name_field)
+    && !name.is_empty()
+  {
+    let user = get(&get(&options, "publicKey")?, "user")?;
+    set(&user, "name", &name.as_str().into())?;
+    set(&user, "displayName", &name.as_str().into())?;
+  }
+
+  Ok(options)
+}
+
+/// Writes `value` to the result field `name` and submits its form.
+fn submit_result(document: &Document, name: &str, value: &str) -> Result<(), JsValue> {
+  let result = field(document, name)?;
+  set(&result, "value", &value.into())?;
+
+  result
+    .closest("form")?
+    .ok_or_else(|| JsValue::from_str(&format!("Form field {name} is not in a form")))?
+    .dyn_into::<HtmlFormElement>()?
+    .request_submit()
+}
+
+/// Runs the ceremony for a Kratos `onclickTrigger`, e.g. `oryWebAuthnLogin`, natively rather than
+/// through `webauthn.js`, then submits the credential with the trigger's form.
+pub async fn on_click(trigger: &str) -> Result<(), JsValue> {
+  let document = document()?;
+
+  let (kind, options, result) = match trigger {
+    "oryWebAuthnRegistration" => (
+      Ceremony::Create,
+      JSON::parse(&field_value(&document, "webauthn_register_trigger")?)?,
+      "webauthn_register",
+    ),
+    "oryWebAuthnLogin" => (
+      Ceremony::Get,
+      JSON::parse(&field_value(&document, "webauthn_login_trigger")?)?,
+      "webauthn_login",
+    ),
+    "oryPasskeyRegistration" => (
+      Ceremony::Create,
+      passkey_create_options(&document)?,
+      "passkey_register",
+    ),
+    "oryPasskeySettingsRegistration" => (
+      Ceremony::Create,
+      passkey_create_options(&document)?,
+      "passkey_settings_register",
+    ),
+    "oryPasskeyLogin" => (
+      Ceremony::Get,
+      JSON::parse(&field_value(&document, "passkey_challenge")?)?,
+      "passkey_login",
+    ),
+    _ => return Err(JsValue::from_str(&format!("Unknown trigger {trigger}"))),
+  };
+
+  let credential = ceremony(kind, options).await?;
+  submit_result(&document, result, &credential)
+}
+
+/// Whether the browser can offer passkeys in autofill,
+/// `PublicKeyCredential.isConditionalMediationAvailable()`.
+async fn conditional_mediation_available() -> Result<bool, JsValue> {
+  let window = web_sys::window().ok_or_else(|| JsValue::from_str("Could not access window"))?;
+  let class = get(&window, "PublicKeyCredential")?;
+  if class.is_undefined() {
+    return Ok(false);
+  }
+
+  let Ok(check) = get(&class, "isConditionalMediationAvailable")?.dyn_into::<Function>() else {
+    return Ok(false);
+  };
+  let promise = check.call0(&class)?.dyn_into::<Promise>()?;
+  Ok(JsFuture::from(promise).await?.is_truthy())
+}
+
+/// Runs a Kratos `onloadTrigger` natively rather than through the `webauthn.js` helpers, which
+/// post the form natively and would reload the app.
+///
+/// Only `oryPasskeyLoginAutocompleteInit` runs on load: it offers passkeys in the identifier's
+/// autofill, where the browser supports it, and signs in once one is picked, unless `signal`
+/// aborts the request first. Other ceremonies need a user gesture and are ignored.
+pub async fn on_load(trigger: &str, signal: &AbortSignal) -> Result<(), JsValue> {
+  if trigger != "oryPasskeyLoginAutocompleteInit" {
+    warn!("Ignoring onload trigger {trigger}");
+    return Ok(());
+  }
+  if !conditional_mediation_available().await? {
+    return Ok(());
+  }
+
+  let document = document()?;
+  let options = JSON::parse(&field_value(&document, "passkey_challenge")?)?;
+  set(&options, "mediation", &"conditional".into())?;
+  set(&options, "signal", signal)?;
+  let credential = ceremony(Ceremony::Get, options).await?;
+  submit_result(&document, "passkey_login", &credential)
+}
+
+/// Kratos' name for a trigger enum, e.g. `oryWebAuthnLogin`.
+pub fn trigger_name(trigger: &impl Serialize) -> String {
+  serde_json::to_value(trigger)
+    .ok()
+    .and_then(|value| value.as_str().map(str::to_owned))
+    .unwrap_or_default()
+}
+
+/// Appends Kratos' script to `<head>`, where, unlike in the Dioxus DOM, the browser executes it.
+pub fn load_script(attrs: &UiNodeScriptAttributes) -> Result<(), JsValue> {
+  let document = document()?;
+  if document.get_element_by_id(&attrs.id).is_some() {
+    return Ok(());
+  }
+
+  let script = document.create_element("script")?;
+  script.set_id(&attrs.id);
+  for (name, value) in [
+    ("src", &attrs.src),
+    ("type", &attrs.r#type),
+    ("crossorigin", &attrs.crossorigin),
+    ("integrity", &attrs.integrity),
+    ("nonce", &attrs.nonce),
+    ("referrerpolicy", &attrs.referrerpolicy),
+  ] {
+    if !value.is_empty() {
+      script.set_attribute(name, value)?;
+    }
+  }
+  if attrs.r#async {
+    script.set_attribute("async", "")?;
+  }
+
+  document
+    .head()
+    .ok_or_else(|| JsValue::from_str("Could not access document head"))?
+    .append_child(&script)?;
+  Ok(())
+}